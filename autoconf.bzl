@@ -1,20 +1,58 @@
+load("@bazel_skylib//rules:common_settings.bzl", "BuildSettingInfo")
 load("@rules_cc//cc:defs.bzl", "CcInfo", "cc_common")
 load("@rules_cc//cc:find_cc_toolchain.bzl", "CC_TOOLCHAIN_ATTRS", "CC_TOOLCHAIN_TYPE", "use_cc_toolchain", "find_cpp_toolchain")
 
+def _option_value(value):
+    if type(value) == "bool":
+        return "true" if value else "false"
+    if type(value) == "list":
+        return ",".join(value)
+    return str(value)
+
 def _autoconf_impl(ctx):
     out = ctx.outputs.config_out or ctx.actions.declare_file("_%s/config.h" % ctx.label.name)
+    copts = ctx.actions.declare_file("_%s/copts.txt" % ctx.label.name)
+    linkopts = ctx.actions.declare_file("_%s/linkopts.txt" % ctx.label.name)
 
     toolchain = find_cpp_toolchain(ctx)
+    outs = [out]
     args = ctx.actions.args()
     args.add(out, format="--output=%s")
-    args.add(ctx.file.config_in, format="--input=%s")
+    args.add(copts, format="--copts-out=%s")
+    args.add(linkopts, format="--linkopts-out=%s")
+    inputs = [ctx.file.starlark_config] + ctx.files.srcs
+    args.add(ctx.file.starlark_config.owner.workspace_root or ".", format="--load-path=%s")
+    roots = {}
+    for src in ctx.files.srcs:
+        if src.owner.workspace_root:
+            # canonical names of module repositories are the module name followed
+            # by `+` or `~`, which is how configs refer to them
+            name = src.owner.workspace_name.split("+")[0].split("~")[0]
+            roots[name] = src.owner.workspace_root
+    for name, root in roots.items():
+        args.add("--load-path=%s=%s" % (name, root))
+    if ctx.file.config_in:
+        args.add(ctx.file.config_in, format="--input=%s")
+        inputs.append(ctx.file.config_in)
+    for template, name in ctx.attr.templates.items():
+        template = template.files.to_list()[0]
+        args.add("--input=%s=%s" % (name, template.path))
+        inputs.append(template)
+    for name in ctx.attr.templates.values() + ctx.attr.outs:
+        extra = ctx.actions.declare_file("_%s/%s" % (ctx.label.name, name))
+        args.add("--output=%s=%s" % (name, extra.path))
+        outs.append(extra)
     args.add(ctx.file.starlark_config, format="--config=%s")
     args.add(toolchain.compiler_executable, format="--cc=%s")
     args.add(toolchain.compiler, format="--compiler=%s")
     args.add_all(toolchain.built_in_include_directories, format_each = "--isystem=%s")
+    for name, value in ctx.attr.options.items():
+        args.add("--option=%s=%s" % (name, value))
+    for flag, name in ctx.attr.option_flags.items():
+        args.add("--option=%s=%s" % (name, _option_value(flag[BuildSettingInfo].value)))
     ctx.actions.run(
-        inputs = depset([ctx.file.config_in, ctx.file.starlark_config], transitive = [toolchain.all_files]),
-        outputs = [out],
+        inputs = depset(inputs, transitive = [toolchain.all_files]),
+        outputs = outs + [copts, linkopts],
         arguments = [args],
         executable = ctx.executable._starconf,
         toolchain = CC_TOOLCHAIN_TYPE,
@@ -24,26 +62,69 @@ def _autoconf_impl(ctx):
 
     includes = None
     if ctx.attr.include:
-        includes = depset([out.dirname])
+        includes = depset([f.dirname for f in outs])
 
-    return CcInfo(
-        compilation_context = cc_common.create_compilation_context(
-            headers = depset([out]),
-            includes = includes,
-            quote_includes = includes
-        )
+    # Flags are only known after execution, link flags are passed as a response file.
+    # CcInfo can't carry compile flags, only defines known during analysis, so the
+    # copts file is a header of the compilation context, which makes it an input of
+    # every dependent compile action. Dependents pass it with `copts = ["@$(execpath ...)"]`
+    # of a filegroup selecting the `copts` output group.
+    linker_input = cc_common.create_linker_input(
+        owner = ctx.label,
+        user_link_flags = ["@" + linkopts.path],
+        additional_inputs = depset([linkopts]),
     )
 
+    return [
+        CcInfo(
+            compilation_context = cc_common.create_compilation_context(
+                headers = depset(outs + [copts]),
+                includes = includes,
+                quote_includes = includes
+            ),
+            linking_context = cc_common.create_linking_context(
+                linker_inputs = depset([linker_input]),
+            ),
+        ),
+        OutputGroupInfo(
+            copts = depset([copts]),
+            linkopts = depset([linkopts]),
+        ),
+    ]
+
 autoconf = rule(
     implementation = _autoconf_impl,
     attrs = {
         "starlark_config": attr.label(mandatory = True, allow_single_file = True),
-        "config_in": attr.label(mandatory = True, allow_single_file = True),
+        "srcs": attr.label_list(allow_files = [".star"], doc = """\
+`.star` files loaded by `starlark_config`, with a path relative to the loading file,
+`//pkg:file.star` or `@repo//pkg:file.star` for files of other repositories.
+"""),
+        "config_in": attr.label(allow_single_file = True, doc = """\
+Template returned by `config_in()`, can be omitted when the header is generated
+with `configure_file(input=None, ...)`.
+"""),
         "config_out": attr.output(),
+        "templates": attr.label_keyed_string_dict(allow_files = True, doc = """\
+Additional templates mapped to the name of the header generated from them, the
+template and the header are returned by `config_in(name)` and `config_out(name)`.
+"""),
+        "outs": attr.string_list(doc = """\
+Additional headers generated without a template, returned by `config_out(name)`.
+"""),
         "include": attr.bool(default = True, doc = """\
 Whether to add config basename directory as include path to allow importing
 of config.h as `<config.h>` or `"config.h"` througout the repository.
 """),
+        "options": attr.string_dict(doc = """\
+Values of options declared with `option()` in the starlark config, passed as `--option name=value`.
+"""),
+        "option_flags": attr.label_keyed_string_dict(
+            providers = [BuildSettingInfo],
+            doc = """\
+Build settings (e.g. `string_flag`, `bool_flag`) mapped to the option name they provide a value for.
+""",
+        ),
         "_starconf": attr.label(
             default = "@starconf//:starconf",
             executable = True,
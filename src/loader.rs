@@ -0,0 +1,176 @@
+//! Evaluates configs and the modules they `load()`.
+//!
+//! Modules are loaded by path relative to the loading file (`checks.star`,
+//! `:checks.star`), or by label: `//pkg:checks.star` is relative to the root of the
+//! loading module and `@name//pkg:checks.star` to the load path named `name`.
+//! `@starconf//` modules are bundled in the binary unless a load path is named
+//! `starconf`.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use starlark::environment::{FrozenModule, Globals, Module};
+use starlark::eval::{Evaluator, FileLoader};
+use starlark::syntax::{AstModule, Dialect};
+
+use crate::values::store::{NamedPath, Store};
+
+/// Modules loaded with `@starconf//name`.
+const BUNDLED: &[(&str, &str)] = &[("ac.star", include_str!("prelude/ac.star"))];
+
+fn bundled(path: &str) -> Option<&'static str> {
+    let name = path.strip_prefix("@starconf//")?;
+    BUNDLED
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, source)| *source)
+}
+
+/// Evaluates a config, every loaded module is evaluated once and frozen.
+pub(crate) struct Loader<'a> {
+    globals: Globals,
+    dialect: Dialect,
+    store: &'a Store,
+    /// Roots of `//` labels for the unnamed one and of `@name//` labels.
+    roots: Vec<NamedPath>,
+    modules: RefCell<HashMap<PathBuf, FrozenModule>>,
+    /// Keys of the modules being evaluated, to report load cycles.
+    loading: RefCell<Vec<PathBuf>>,
+}
+
+/// Resolves the loads of a module in `dir` whose `//` labels are relative to `root`.
+struct ModuleLoader<'l, 'a> {
+    loader: &'l Loader<'a>,
+    dir: PathBuf,
+    root: PathBuf,
+}
+
+impl<'a> Loader<'a> {
+    pub(crate) fn new(
+        globals: Globals,
+        dialect: Dialect,
+        store: &'a Store,
+        roots: Vec<NamedPath>,
+    ) -> Self {
+        Self {
+            globals,
+            dialect,
+            store,
+            roots,
+            modules: Default::default(),
+            loading: Default::default(),
+        }
+    }
+
+    /// Evaluates the config at `path`, its `//` labels are relative to the unnamed
+    /// load path or to the working directory.
+    pub(crate) fn eval_config(&self, path: &str) -> starlark::Result<()> {
+        let root = self.root(None)?;
+        let key = fs::canonicalize(path).with_context(|| format!("failed to read {}", path))?;
+        *self.loading.borrow_mut() = vec![key];
+        self.eval(path, &root, &Module::new())
+    }
+
+    fn eval(&self, path: &str, root: &Path, module: &Module) -> starlark::Result<()> {
+        let content = match bundled(path) {
+            Some(source) => source.to_string(),
+            None => fs::read_to_string(path).with_context(|| format!("failed to read {}", path))?,
+        };
+        let ast = AstModule::parse(path, content, &self.dialect)?;
+        let loader = ModuleLoader {
+            loader: self,
+            dir: Path::new(path).parent().unwrap_or(Path::new("")).to_owned(),
+            root: root.to_owned(),
+        };
+        let mut eval = Evaluator::new(module);
+        eval.extra = Some(self.store);
+        eval.set_loader(&loader);
+        eval.eval_module(ast, &self.globals)?;
+        Ok(())
+    }
+
+    fn root(&self, name: Option<&str>) -> anyhow::Result<PathBuf> {
+        match self.roots.iter().find(|r| r.name.as_deref() == name) {
+            Some(root) => Ok(PathBuf::from(&root.path)),
+            None if name.is_none() => Ok(PathBuf::from(".")),
+            None => anyhow::bail!("no --load-path named {}", name.unwrap_or_default()),
+        }
+    }
+
+    fn load(&self, path: &Path, root: &Path) -> starlark::Result<FrozenModule> {
+        let name = path.to_string_lossy().into_owned();
+        let key = match bundled(&name) {
+            Some(_) => path.to_owned(),
+            None => fs::canonicalize(path)
+                .with_context(|| format!("failed to load {}", path.display()))?,
+        };
+        if let Some(module) = self.modules.borrow().get(&key) {
+            return Ok(module.clone());
+        }
+
+        if self.loading.borrow().contains(&key) {
+            let cycle: Vec<_> = self
+                .loading
+                .borrow()
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect();
+            return Err(
+                anyhow::anyhow!("load cycle: {} -> {}", cycle.join(" -> "), key.display()).into(),
+            );
+        }
+        self.loading.borrow_mut().push(key.clone());
+        let module = Module::new();
+        let result = self.eval(&name, root, &module);
+        self.loading.borrow_mut().pop();
+        result?;
+
+        let module = module.freeze()?;
+        self.modules.borrow_mut().insert(key, module.clone());
+        Ok(module)
+    }
+}
+
+/// Path of `pkg:file.star` or `:file.star` relative to the root.
+fn package_path(label: &str) -> String {
+    label
+        .replacen(':', "/", 1)
+        .trim_start_matches('/')
+        .to_string()
+}
+
+impl ModuleLoader<'_, '_> {
+    /// Returns the file loaded by `label` and the root of its own labels.
+    fn resolve(&self, label: &str) -> anyhow::Result<(PathBuf, PathBuf)> {
+        if let Some(label) = label.strip_prefix('@') {
+            let (name, path) = label
+                .split_once("//")
+                .with_context(|| format!("invalid label @{}, expected @name//pkg:file", label))?;
+            let name = Some(name).filter(|n| !n.is_empty());
+            if name == Some("starconf") && self.loader.root(name).is_err() {
+                let path = format!("@starconf//{}", package_path(path));
+                if bundled(&path).is_none() {
+                    anyhow::bail!("no bundled module {}", path)
+                }
+                return Ok((PathBuf::from(path), PathBuf::from(".")));
+            }
+            let root = self.loader.root(name)?;
+            Ok((root.join(package_path(path)), root))
+        } else if let Some(path) = label.strip_prefix("//") {
+            Ok((self.root.join(package_path(path)), self.root.clone()))
+        } else {
+            let path = label.strip_prefix(':').unwrap_or(label);
+            Ok((self.dir.join(path), self.root.clone()))
+        }
+    }
+}
+
+impl FileLoader for ModuleLoader<'_, '_> {
+    fn load(&self, label: &str) -> starlark::Result<FrozenModule> {
+        let (path, root) = self.resolve(label)?;
+        self.loader.load(&path, &root)
+    }
+}
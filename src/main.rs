@@ -1,34 +1,118 @@
 #![allow(clippy::all)]
+mod loader;
 mod meson;
 mod values;
 
+use anyhow::Context;
+use clap::Args;
 use clap::Parser;
+use clap::Subcommand;
+use clap::ValueEnum;
 use clap::ValueHint;
 // use meson::translate_to_starlark;
 
 use std::cell::RefCell;
-use std::fs;
 
+use starlark::collections::SmallMap;
 use starlark::environment::GlobalsBuilder;
 use starlark::environment::LibraryExtension;
-use starlark::environment::Module;
-use starlark::eval::Evaluator;
+use starlark::syntax::Dialect;
 use starlark::syntax::DialectTypes;
-use starlark::syntax::{AstModule, Dialect};
 
-use values::store::{DDependency, Store};
+use loader::Loader;
+use values::configure_file::write_if_changed;
+use values::project::Arguments;
+use values::store::{DDependency, DOption, NamedPath, Store};
 
 #[derive(Parser)]
-#[command(version, about, long_about = None)]
+#[command(
+    version,
+    about,
+    long_about = None,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 struct CLI {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[clap(flatten)]
+    eval: Option<EvalArgs>,
+
+    /// Output returned by `config_out()`, repeated for several outputs
+    #[clap(
+        long = "output",
+        value_name = "[NAME=]PATH",
+        value_parser = clap::value_parser!(NamedPath),
+        required = true,
+        action = clap::ArgAction::Append
+    )]
+    outputs: Vec<NamedPath>,
+
+    /// Fail when a template and its configuration_data don't use the same keys, see `configure_file(strict=)`
+    #[clap(long = "strict")]
+    strict: bool,
+
+    /// File to write flags added with `add_project_arguments` to, one per line or JSON for `.json` paths
+    #[clap(long = "copts-out")]
+    copts_out: Option<String>,
+
+    /// File to write flags added with `add_project_link_arguments` to, one per line or JSON for `.json` paths
+    #[clap(long = "linkopts-out")]
+    linkopts_out: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Reports which keys of the templates passed to `configure_file` are set by the
+    /// config, left undefined because they are missing, or set but never used
+    Coverage {
+        #[clap(flatten)]
+        eval: EvalArgs,
+
+        /// Outputs named by `config_out()`, they are not written
+        #[clap(
+            long = "output",
+            value_name = "[NAME=]PATH",
+            value_parser = clap::value_parser!(NamedPath),
+            action = clap::ArgAction::Append
+        )]
+        outputs: Vec<NamedPath>,
+
+        #[clap(long = "format", value_enum, default_value_t = CoverageFormat::Text)]
+        format: CoverageFormat,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum CoverageFormat {
+    Text,
+    Json,
+}
+
+// Arguments needed to evaluate a config, shared by all commands.
+#[derive(Args)]
+struct EvalArgs {
     #[clap(short = 'c', long = "config")]
     config: String,
 
-    #[clap(long = "input")]
-    config_in: String,
+    /// Root of `//pkg:file.star` labels passed to `load()`, or of `@NAME//pkg:file.star` labels when named
+    #[clap(
+        long = "load-path",
+        value_name = "[NAME=]DIR",
+        value_parser = clap::value_parser!(NamedPath),
+        action = clap::ArgAction::Append
+    )]
+    load_paths: Vec<NamedPath>,
 
-    #[clap(long = "output")]
-    config_out: String,
+    /// Template returned by `config_in()`, repeated for several templates, optional when the header is generated with `configure_file(input=None)`
+    #[clap(
+        long = "input",
+        value_name = "[NAME=]PATH",
+        value_parser = clap::value_parser!(NamedPath),
+        action = clap::ArgAction::Append
+    )]
+    inputs: Vec<NamedPath>,
 
     #[clap(long = "compiler")]
     compiler_type: String,
@@ -51,40 +135,127 @@ struct CLI {
     #[clap(
            short = 'd',
            long = "dependency",
-           value_name = "NAME=SEMVER",
+           value_name = "NAME=VERSION",
            value_parser = clap::value_parser!(DDependency),
            value_hint = ValueHint::Other,
            action = clap::ArgAction::Append
        )]
     dependencies: Vec<DDependency>,
+
+    #[clap(
+           long = "option",
+           value_name = "NAME=VALUE",
+           value_parser = clap::value_parser!(DOption),
+           value_hint = ValueHint::Other,
+           action = clap::ArgAction::Append
+       )]
+    options: Vec<DOption>,
+}
+
+fn write_flags(path: &str, arguments: &[Arguments]) -> anyhow::Result<()> {
+    let content = if path.ends_with(".json") {
+        let mut by_language: SmallMap<String, Vec<String>> = SmallMap::new();
+        for arguments in arguments {
+            for language in &arguments.languages {
+                by_language
+                    .entry(language.clone())
+                    .or_default()
+                    .extend(arguments.flags.iter().cloned());
+            }
+        }
+        let object: serde_json::Map<String, serde_json::Value> = by_language
+            .into_iter()
+            .map(|(language, flags)| (language, flags.into()))
+            .collect();
+        serde_json::to_string_pretty(&object)? + "\n"
+    } else {
+        // flags added for several languages are written once, and are otherwise not
+        // deduplicated, `-framework A -framework B` needs every token
+        arguments
+            .iter()
+            .flat_map(|arguments| &arguments.flags)
+            .map(|f| format!("{}\n", f))
+            .collect()
+    };
+    write_if_changed(path, &content).with_context(|| format!("failed to write {}", path))
+}
+
+fn print_coverage(reports: &[serde_json::Value], format: CoverageFormat) {
+    if let CoverageFormat::Json = format {
+        println!("{}", serde_json::to_string_pretty(reports).unwrap());
+        return;
+    }
+    for report in reports {
+        let input = report["input"].as_str().unwrap_or("<generated>");
+        let keys = |name: &str| report[name].as_array().cloned().unwrap_or_default();
+        let (set, undef, unused) = (keys("set"), keys("undef"), keys("unused"));
+        println!(
+            "{}: {} set, {} undef, {} unused",
+            input,
+            set.len(),
+            undef.len(),
+            unused.len()
+        );
+        for key in &undef {
+            println!(
+                "  undef  {}:{} {}",
+                input,
+                key["line"],
+                key["key"].as_str().unwrap_or_default()
+            );
+        }
+        for key in &unused {
+            println!("  unused {}", key.as_str().unwrap_or_default());
+        }
+    }
 }
 
 fn main() {
     let args = CLI::parse();
 
+    match args.command {
+        Some(Command::Coverage {
+            eval,
+            outputs,
+            format,
+        }) => {
+            let store = evaluate(eval, outputs, false, true);
+            let reports = store.coverage.unwrap().into_inner();
+            print_coverage(&reports, format);
+        }
+        None => {
+            let store = evaluate(args.eval.unwrap(), args.outputs, args.strict, false);
+            let arguments = store.project_arguments.borrow();
+            if let Some(path) = &args.copts_out {
+                write_flags(path, &arguments.compile).unwrap();
+            }
+            if let Some(path) = &args.linkopts_out {
+                write_flags(path, &arguments.link).unwrap();
+            }
+        }
+    }
+}
+
+fn evaluate(args: EvalArgs, outputs: Vec<NamedPath>, strict: bool, coverage: bool) -> Store {
     let dialect = Dialect {
         enable_def: true,
         enable_f_strings: true,
         enable_keyword_only_arguments: true,
         enable_lambda: true,
         enable_load_reexport: false,
-        enable_load: false,
+        enable_load: true,
         enable_top_level_stmt: true,
         enable_types: DialectTypes::Enable,
         enable_positional_only_arguments: true,
         ..Default::default()
     };
 
-    let content = fs::read_to_string(&args.config).unwrap();
-
-    let ast = if args.config.ends_with("meson.build") {
+    if args.config.ends_with("meson.build") {
         // let tr = translate_to_starlark(&content).unwrap();
 
         // AstModule::parse("meson.build", tr, &dialect).unwrap()
         panic!("Not supported")
-    } else {
-        AstModule::parse(&args.config, content, &dialect).unwrap()
-    };
+    }
 
     let mut globals = GlobalsBuilder::extended_by(&[
         LibraryExtension::StructType,
@@ -107,8 +278,6 @@ fn main() {
     ]);
     values::register_toplevels(&mut globals);
 
-    let module = Module::new();
-
     let mut cc_args = vec![];
 
     for arg in args.quote_include {
@@ -124,16 +293,111 @@ fn main() {
         cc_compiler: args.compiler_type,
         cc_executable: args.cc,
         cc_args: cc_args,
-        config_in: args.config_in,
-        config_out: args.config_out,
+        inputs: args.inputs,
+        outputs: outputs,
+        options: args.options,
+        declared_options: Default::default(),
+        project: Default::default(),
+        project_arguments: Default::default(),
+        strict: strict,
+        coverage: coverage.then(Default::default),
+        last_check: Default::default(),
     };
 
     {
-        let mut eval = Evaluator::new(&module);
-        eval.extra = Some(&store);
-        let value = eval.eval_module(ast, &globals.build());
+        let loader = Loader::new(globals.build(), dialect, &store, args.load_paths);
+        let value = loader.eval_config(&args.config);
         if value.is_err() {
             panic!("{:?}", value.unwrap_err());
         }
     }
+
+    let declared = store.declared_options.borrow();
+    let unknown: Vec<&str> = store
+        .options
+        .iter()
+        .filter(|o| !declared.contains_key(&o.name))
+        .map(|o| o.name.as_str())
+        .collect();
+    if !unknown.is_empty() {
+        let available: Vec<String> = declared
+            .iter()
+            .map(|(name, option)| match &option.description {
+                Some(description) => format!("\n  {}: {}", name, description),
+                None => format!("\n  {}", name),
+            })
+            .collect();
+        if available.is_empty() {
+            panic!(
+                "unknown options: {}, no option is declared",
+                unknown.join(", ")
+            );
+        }
+        panic!(
+            "unknown options: {}, declared options:{}",
+            unknown.join(", "),
+            available.concat()
+        );
+    }
+    drop(declared);
+
+    store
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arguments(languages: &[&str], flags: &[&str]) -> Arguments {
+        Arguments {
+            languages: languages.iter().map(|l| l.to_string()).collect(),
+            flags: flags.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn write_flags_keeps_paired_flags() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("copts.txt");
+        let path = path.to_str().unwrap();
+        let flags = [
+            arguments(&["c"], &["-framework", "A", "-framework", "B"]),
+            arguments(&["cpp"], &["-Xclang", "-foo", "-Xclang", "-bar"]),
+        ];
+        write_flags(path, &flags).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "-framework\nA\n-framework\nB\n-Xclang\n-foo\n-Xclang\n-bar\n"
+        );
+    }
+
+    #[test]
+    fn write_flags_once_for_several_languages() {
+        let dir = tempfile::tempdir().unwrap();
+        let flags = [
+            arguments(&["c", "cpp"], &["-DFOO", "-framework", "A"]),
+            arguments(&["cpp"], &["-framework", "A"]),
+        ];
+
+        let path = dir.path().join("copts.txt");
+        let path = path.to_str().unwrap();
+        write_flags(path, &flags).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "-DFOO\n-framework\nA\n-framework\nA\n"
+        );
+
+        let path = dir.path().join("copts.json");
+        let path = path.to_str().unwrap();
+        write_flags(path, &flags).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "c": ["-DFOO", "-framework", "A"],
+                "cpp": ["-DFOO", "-framework", "A", "-framework", "A"],
+            })
+        );
+    }
 }
@@ -7,13 +7,15 @@ use std::process::Stdio;
 
 use allocative::Allocative;
 use anyhow::Context;
-use starlark;
+use either::Either;
 use starlark::any::ProvidesStaticType;
 use starlark::environment::Methods;
 use starlark::environment::MethodsBuilder;
 use starlark::environment::MethodsStatic;
+use starlark::eval::Evaluator;
 use starlark::starlark_module;
 use starlark::starlark_simple_value;
+use starlark::values::list::UnpackList;
 use starlark::values::tuple::UnpackTuple;
 use starlark::values::FreezeResult;
 use starlark::values::StarlarkValue;
@@ -26,6 +28,9 @@ use starlark_derive::NoSerialize;
 use starlark_derive::Trace;
 use tempfile::NamedTempFile;
 
+use crate::values::feature::Required;
+use crate::values::provenance::record_check;
+
 #[derive(Clone, Default, Debug, Trace, Freeze, ProvidesStaticType, Allocative, NoSerialize)]
 #[repr(C)]
 pub struct Compiler {
@@ -63,12 +68,20 @@ starlark_simple_value!(Compiler);
 
 impl Compiler {
     pub(self) fn _compiles(&self, code: String, args: Option<Vec<String>>) -> bool {
+        self._compiles_as("c++", code, args)
+    }
+    pub(self) fn _compiles_as(
+        &self,
+        language: &str,
+        code: String,
+        args: Option<Vec<String>>,
+    ) -> bool {
         let mut cmd = Command::new(&self.executable);
         cmd.arg("-O0") // do not optimize output
             .arg("-v")
             .arg("-c")
             .arg("-x")
-            .arg("c++")
+            .arg(language)
             .arg("-") // read the program from stdin
             .arg("-o")
             .arg("/dev/null")
@@ -97,15 +110,95 @@ impl Compiler {
         }
         result.unwrap().success()
     }
+    pub(self) fn _has_type(
+        &self,
+        type_name: &str,
+        prefix: &str,
+        args: Option<Vec<String>>,
+    ) -> bool {
+        self._compiles(
+            format!(
+                r#"
+{prefix}
+int main(void) {{
+    (void) sizeof({});
+    return 0;
+}}"#,
+                type_name,
+                prefix = prefix
+            ),
+            args,
+        )
+    }
+    /// Whether `func` is a compiler builtin, with or without its `__builtin_` prefix.
+    pub(self) fn _has_builtin(&self, func: &str, prefix: &str) -> bool {
+        let is_builtin = func.starts_with("__builtin_");
+        let no_includes = !prefix.contains("#include");
+        // Detect function as a built-in
+        //
+        // Some functions like alloca() are defined as compiler built-ins which
+        // are inlined by the compiler and you can't take their address, so we
+        // need to look for them differently. On nice compilers like clang, we
+        // can just directly use the __has_builtin() macro.
+        self._compiles(
+            format!(
+                r#"{prefix}
+        int main(void) {{
+
+        /* With some toolchains (MSYS2/mingw for example) the compiler
+         * provides various builtins which are not really implemented and
+         * fall back to the stdlib where they aren't provided and fail at
+         * build/link time. In case the user provides a header, including
+         * the header didn't lead to the function being defined, and the
+         * function we are checking isn't a builtin itself we assume the
+         * builtin is not functional and we just error out. */
+        #if !{no_includes} && !defined({func}) && !{is_builtin}
+            #error "No definition for {__builtin_}{func} found in the prefix"
+        #endif
+
+        #ifdef __has_builtin
+            #if !__has_builtin({__builtin_}{func})
+                #error "{__builtin_}{func} not found"
+            #endif
+        #elif ! defined({func})
+            {__builtin_}{func};
+        #endif
+        return 0;
+        }}"#,
+                func = func,
+                prefix = prefix,
+                __builtin_ = if is_builtin { "" } else { "__builtin_" },
+                is_builtin = std::convert::Into::<i32>::into(is_builtin),
+                no_includes = std::convert::Into::<i32>::into(no_includes),
+            ),
+            None,
+        )
+    }
+    /// Returns the first of `keywords` with which `program` compiles as C, `KW` is
+    /// replaced by the keyword.
+    pub(self) fn c_keyword<'k>(
+        &self,
+        keywords: &[&'k str],
+        program: &str,
+        args: Option<Vec<String>>,
+    ) -> Option<&'k str> {
+        keywords
+            .iter()
+            .copied()
+            .find(|keyword| self._compiles_as("c", program.replace("KW", keyword), args.clone()))
+    }
     pub(self) fn compile_and_run(self, code: String) -> anyhow::Result<Vec<u8>> {
-        let tmp = NamedTempFile::new().context("failed to create a temp file")?;
+        // only keep the path, an open handle makes exec fail with ETXTBSY on linux
+        let tmp = NamedTempFile::new()
+            .context("failed to create a temp file")?
+            .into_temp_path();
         let mut cmd = Command::new(&self.executable)
             .arg("-v")
             .arg("-x")
             .arg("c++")
             .arg("-") // read the program from stdin
             .arg("-o")
-            .arg(tmp.path())
+            .arg(&tmp)
             .args(self.args)
             .stdin(Stdio::piped())
             .stderr(Stdio::piped())
@@ -128,7 +221,7 @@ impl Compiler {
             )
         }
 
-        let cmd = Command::new(tmp.path())
+        let cmd = Command::new(&tmp)
             .stdout(Stdio::piped())
             .spawn()
             .context("failed to spawn the sizeof program")?;
@@ -137,6 +230,295 @@ impl Compiler {
     }
 }
 
+// Test programs of autoconf's AC_C_INLINE, AC_C_RESTRICT, AC_C_CONST, AC_C_VOLATILE
+// and AC_C_TYPEOF.
+const INLINE_PROGRAM: &str = r#"
+typedef int foo_t;
+static KW foo_t static_foo (void) { return 0; }
+KW foo_t foo (void) { return 0; }
+"#;
+
+const RESTRICT_PROGRAM: &str = r#"
+typedef int *int_ptr;
+int foo (int_ptr KW ip) { return ip[0]; }
+int bar (int [KW]); /* Catch GCC bug 14050.  */
+int bar (int ip[KW]) { return ip[0]; }
+int main (void) {
+    int s[1];
+    int *KW t = s;
+    t[0] = 0;
+    return foo (t) + bar (t);
+}
+"#;
+
+const CONST_PROGRAM: &str = r#"
+int main (void) {
+    const int x = 0;
+    char const *const p = "";
+    const char **pp = 0;
+    return x + (*p != 0) + (pp != 0);
+}
+"#;
+
+const VOLATILE_PROGRAM: &str = r#"
+int main (void) {
+    KW int x = 0;
+    int *KW y = (int *) 0;
+    return x + !y;
+}
+"#;
+
+const TYPEOF_PROGRAM: &str = r#"
+int main (void) {
+    int value = 0;
+    KW (value) copy = value;
+    KW (&value) ptr = &copy;
+    return *ptr;
+}
+"#;
+
+// Headers of the types autoconf's AC_TYPE_* macros look for.
+const TYPE_INCLUDES: &str = r#"
+#include <stddef.h>
+#include <stdint.h>
+#include <sys/types.h>
+"#;
+
+const SIGNED_INTEGERS: &[&str] = &[
+    "signed char",
+    "short int",
+    "int",
+    "long int",
+    "long long int",
+];
+
+const UNSIGNED_INTEGERS: &[&str] = &[
+    "unsigned char",
+    "unsigned short int",
+    "unsigned int",
+    "unsigned long int",
+    "unsigned long long int",
+];
+
+/// The size expression and signedness of the integer types replaced by a builtin type
+/// of the same size, e.g. `uint64_t` like `AC_TYPE_UINT64_T`.
+fn integer_type(type_name: &str) -> Option<(String, bool)> {
+    let (signed, name) = match type_name.strip_prefix('u') {
+        Some(name) => (false, name),
+        None => (true, type_name),
+    };
+    let size = match name {
+        "int8_t" => "1".to_string(),
+        "int16_t" => "2".to_string(),
+        "int32_t" => "4".to_string(),
+        "int64_t" => "8".to_string(),
+        "intptr_t" => "sizeof(void *)".to_string(),
+        "intmax_t" => "sizeof(long long int)".to_string(),
+        _ => return None,
+    };
+    Some((size, signed))
+}
+
+/// The builtin types tried in order to replace an integer type, `intmax_t` prefers
+/// `long long int` like `AC_TYPE_INTMAX_T` even when `long int` has the same size.
+fn integer_candidates(type_name: &str, signed: bool) -> Vec<String> {
+    let integers = if signed {
+        SIGNED_INTEGERS
+    } else {
+        UNSIGNED_INTEGERS
+    };
+    let mut candidates: Vec<String> = integers.iter().map(|t| t.to_string()).collect();
+    if type_name.ends_with("intmax_t") {
+        candidates.rotate_right(1);
+    }
+    candidates
+}
+
+/// The replacement autoconf defines for the other types it checks, e.g. `size_t`
+/// like `AC_TYPE_SIZE_T`.
+fn fixed_fallback(type_name: &str) -> Option<&'static str> {
+    match type_name {
+        "size_t" => Some("unsigned int"),
+        "ssize_t" | "pid_t" | "mode_t" | "uid_t" | "gid_t" => Some("int"),
+        "off_t" => Some("long int"),
+        _ => None,
+    }
+}
+
+// Test programs of function attributes, from meson's c_function_attributes.py, they
+// are compiled as C.
+const FUNCTION_ATTRIBUTES: &[(&str, &str)] = &[
+    (
+        "alias",
+        r#"int foo(void) { return 0; }
+int bar(void) __attribute__((alias("foo")));"#,
+    ),
+    ("aligned", "int foo(void) __attribute__((aligned(32)));"),
+    (
+        "alloc_size",
+        "void *foo(int a) __attribute__((alloc_size(1)));",
+    ),
+    (
+        "always_inline",
+        "inline int foo(void) __attribute__((always_inline));",
+    ),
+    (
+        "artificial",
+        "inline int foo(void) __attribute__((artificial));",
+    ),
+    ("cold", "int foo(void) __attribute__((cold));"),
+    ("const", "int foo(void) __attribute__((const));"),
+    ("constructor", "int foo(void) __attribute__((constructor));"),
+    (
+        "constructor_priority",
+        "int foo( void ) __attribute__((__constructor__(65535/2)));",
+    ),
+    (
+        "deprecated",
+        r#"int foo(void) __attribute__((deprecated("")));"#,
+    ),
+    ("destructor", "int foo(void) __attribute__((destructor));"),
+    (
+        "dllexport",
+        "__declspec(dllexport) int foo(void) { return 0; }",
+    ),
+    ("dllimport", "__declspec(dllimport) int foo(void);"),
+    ("error", r#"int foo(void) __attribute__((error("")));"#),
+    (
+        "externally_visible",
+        "int foo(void) __attribute__((externally_visible));",
+    ),
+    (
+        "fallthrough",
+        r#"int foo( void ) {
+  switch (0) {
+    case 1: __attribute__((fallthrough));
+    case 2: break;
+  }
+  return 0;
+};"#,
+    ),
+    ("flatten", "int foo(void) __attribute__((flatten));"),
+    (
+        "format",
+        "int foo(const char * p, ...) __attribute__((format(printf, 1, 2)));",
+    ),
+    (
+        "format_arg",
+        "char * foo(const char * p) __attribute__((format_arg(1)));",
+    ),
+    (
+        "force_align_arg_pointer",
+        "__attribute__((force_align_arg_pointer)) int foo(void) { return 0; }",
+    ),
+    (
+        "gnu_inline",
+        "inline __attribute__((gnu_inline)) int foo(void) { return 0; }",
+    ),
+    ("hot", "int foo(void) __attribute__((hot));"),
+    (
+        "ifunc",
+        r#"int my_foo(void) { return 0; }
+static int (*resolve_foo(void))(void) { return my_foo; }
+int foo(void) __attribute__((ifunc("resolve_foo")));"#,
+    ),
+    ("leaf", "__attribute__((leaf)) int foo(void) { return 0; }"),
+    ("malloc", "int *foo(void) __attribute__((malloc));"),
+    ("noclone", "int foo(void) __attribute__((noclone));"),
+    (
+        "noinline",
+        "__attribute__((noinline)) int foo(void) { return 0; }",
+    ),
+    ("nonnull", "int foo(char * p) __attribute__((nonnull(1)));"),
+    ("noreturn", "int foo(void) __attribute__((noreturn));"),
+    ("nothrow", "int foo(void) __attribute__((nothrow));"),
+    (
+        "null_terminated_string_arg",
+        "int foo(const char * p) __attribute__((null_terminated_string_arg(1)));",
+    ),
+    (
+        "optimize",
+        "__attribute__((optimize(3))) int foo(void) { return 0; }",
+    ),
+    ("packed", "struct __attribute__((packed)) foo { int bar; };"),
+    ("pure", "int foo(void) __attribute__((pure));"),
+    ("retain", "__attribute__((retain)) int x;"),
+    (
+        "returns_nonnull",
+        "int *foo(void) __attribute__((returns_nonnull));",
+    ),
+    (
+        "section",
+        r#"#if defined(__APPLE__) && defined(__MACH__)
+extern int foo __attribute__((section("__BAR,__bar")));
+#else
+extern int foo __attribute__((section(".bar")));
+#endif"#,
+    ),
+    (
+        "sentinel",
+        "int foo(const char *bar, ...) __attribute__((sentinel));",
+    ),
+    ("unused", "int foo(void) __attribute__((unused));"),
+    ("used", "int foo(void) __attribute__((used));"),
+    (
+        "vector_size",
+        "__attribute__((vector_size(32))); int foo(void) { return 0; }",
+    ),
+    (
+        "visibility",
+        r#"int foo_def(void) __attribute__((visibility("default")));
+int foo_hid(void) __attribute__((visibility("hidden")));
+int foo_int(void) __attribute__((visibility("internal")));"#,
+    ),
+    (
+        "visibility:default",
+        r#"int foo(void) __attribute__((visibility("default")));"#,
+    ),
+    (
+        "visibility:hidden",
+        r#"int foo(void) __attribute__((visibility("hidden")));"#,
+    ),
+    (
+        "visibility:internal",
+        r#"int foo(void) __attribute__((visibility("internal")));"#,
+    ),
+    (
+        "visibility:protected",
+        r#"int foo(void) __attribute__((visibility("protected")));"#,
+    ),
+    ("warning", r#"int foo(void) __attribute__((warning("")));"#),
+    (
+        "warn_unused_result",
+        "int foo(void) __attribute__((warn_unused_result));",
+    ),
+    ("weak", "int foo(void) __attribute__((weak));"),
+    (
+        "weakref",
+        r#"static int foo(void) { return 0; }
+static int var(void) __attribute__((weakref("foo")));"#,
+    ),
+];
+
+/// Flags passed as `args`, either a single flag or a list of flags.
+fn flag_list(args: Option<Either<String, UnpackList<String>>>) -> Option<Vec<String>> {
+    args.map(|args| args.either(|flag| vec![flag], |flags| flags.items))
+}
+
+/// The value defining `keyword` the way autoconf does: `False` leaves it undefined
+/// when it works as is, otherwise it is defined to the working spelling or to
+/// `fallback`.
+fn keyword_define(
+    keyword: &str,
+    found: Option<&str>,
+    fallback: Option<&str>,
+) -> Either<bool, String> {
+    match found.or(fallback) {
+        Some(found) if found != keyword => Either::Right(found.to_string()),
+        _ => Either::Left(false),
+    }
+}
+
 pub(super) fn get_compiler_methods() -> Option<&'static Methods> {
     static RES: MethodsStatic = MethodsStatic::new();
     RES.methods(compiler_methods)
@@ -149,12 +531,19 @@ pub(crate) fn compiler_methods(registry: &mut MethodsBuilder) {
         this: Compiler,
         #[starlark(require = pos)] code: StringValue<'v>,
         #[starlark(require = named)] args: Option<Value<'v>>,
+        #[starlark(require = named)] required: Option<Required>,
         // #[starlark(require = named)] dependencies: Option<Value<'v>>,
         // #[starlark(require = named)] include_directories: Option<Value<'v>>,
         // #[starlark(require = named)] name: Option<StringValue<'v>>,
+        eval: &mut Evaluator<'v, '_, '_>,
     ) -> starlark::Result<bool> {
+        record_check(eval, "cc.compiles(...)".to_string())?;
+        let required = required.unwrap_or_default();
+        if required.skipped() {
+            return Ok(false);
+        }
         let compiles = this._compiles(code.to_string(), args.map(|v| vec![v.to_str()]));
-        return Ok(compiles);
+        return Ok(required.verify("code snippet", compiles)?);
     }
 
     fn has_type<'v>(
@@ -162,29 +551,35 @@ pub(crate) fn compiler_methods(registry: &mut MethodsBuilder) {
         #[starlark(require = pos)] sym: Value<'v>,
         #[starlark(require = named)] prefix: Option<Value<'v>>,
         #[starlark(require = named)] args: Option<Value<'v>>,
+        #[starlark(require = named)] required: Option<Required>,
+        eval: &mut Evaluator<'v, '_, '_>,
     ) -> starlark::Result<bool> {
-        let compiles = this._compiles(
-            format!(
-                r#"
-{prefix}
-int main(void) {{
-    (void) sizeof({});
-    return 0;
-}}"#,
-                sym.to_str(),
-                prefix = prefix.map(|f| f.to_str()).unwrap_or("".into())
-            ),
+        record_check(eval, format!("cc.has_type({:?})", sym.to_str()))?;
+        let required = required.unwrap_or_default();
+        if required.skipped() {
+            return Ok(false);
+        }
+        let compiles = this._has_type(
+            &sym.to_str(),
+            &prefix.map(|f| f.to_str()).unwrap_or("".into()),
             args.map(|v| vec![v.to_str()]),
         );
-        return Ok(compiles);
+        return Ok(required.verify(format!("type `{}`", sym.to_str()), compiles)?);
     }
 
     fn has_header<'v>(
         this: Compiler,
         #[starlark(require = pos)] header_name: String,
         #[starlark(require = named)] prefix: Option<String>,
+        #[starlark(require = named)] required: Option<Required>,
         // #[starlark(require = named)] dependencies: Option<Value<'v>>,
+        eval: &mut Evaluator<'v, '_, '_>,
     ) -> starlark::Result<bool> {
+        record_check(eval, format!("cc.has_header({:?})", header_name))?;
+        let required = required.unwrap_or_default();
+        if required.skipped() {
+            return Ok(false);
+        }
         let compiles = this._compiles(
             format!(
                 r#"{prefix}
@@ -200,7 +595,7 @@ int main(void) {{
             ),
             None,
         );
-        return Ok(compiles);
+        return Ok(required.verify(format!("header `{}`", header_name), compiles)?);
     }
 
     fn has_header_symbol<'v>(
@@ -208,7 +603,17 @@ int main(void) {{
         #[starlark(require = pos)] header_name: String,
         #[starlark(require = pos)] symbol: String,
         #[starlark(require = named)] prefix: Option<String>,
+        #[starlark(require = named)] required: Option<Required>,
+        eval: &mut Evaluator<'v, '_, '_>,
     ) -> starlark::Result<bool> {
+        record_check(
+            eval,
+            format!("cc.has_header_symbol({:?}, {:?})", header_name, symbol),
+        )?;
+        let required = required.unwrap_or_default();
+        if required.skipped() {
+            return Ok(false);
+        }
         let compiles = this._compiles(
             format!(
                 r#"{prefix}
@@ -226,24 +631,100 @@ int main(void) {{
             ),
             None,
         );
-        return Ok(compiles);
+        return Ok(required.verify(
+            format!("symbol `{}` in header `{}`", symbol, header_name),
+            compiles,
+        )?);
+    }
+
+    /// Whether `symbol` is declared once `prefix` is included, as a macro or as
+    /// anything usable in `(void) symbol`, like `AC_CHECK_DECL`. The result is meant
+    /// for `cdata.set10("HAVE_DECL_" + ..., ...)`, which autoconf always defines.
+    ///
+    /// The check is compiled as C, or as C++ with `language = "cpp"` where argument
+    /// types pick one of overloaded functions, e.g. `strerror_r(int, char *, size_t)`
+    /// is checked by calling it with `(int) 0`, `(char *) 0` and `(size_t) 0`.
+    fn has_declaration<'v>(
+        this: &Compiler,
+        #[starlark(require = pos)] symbol: &str,
+        #[starlark(require = named)] prefix: Option<&str>,
+        #[starlark(require = named)] language: Option<&str>,
+        #[starlark(require = named)] required: Option<Required>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> starlark::Result<bool> {
+        record_check(eval, format!("cc.has_declaration({:?})", symbol))?;
+        let language = match language.unwrap_or("c") {
+            "c" => "c",
+            "cpp" => "c++",
+            language => {
+                return Err(
+                    anyhow::anyhow!("invalid language `{}`, expected c or cpp", language).into(),
+                )
+            }
+        };
+        let required = required.unwrap_or_default();
+        if required.skipped() {
+            return Ok(false);
+        }
+        let (name, usage) = match symbol.split_once('(') {
+            Some((name, types)) => {
+                let arguments: Vec<String> = types
+                    .trim_end()
+                    .trim_end_matches(')')
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|t| !t.is_empty())
+                    .map(|t| format!("({}) 0", t))
+                    .collect();
+                let name = name.trim();
+                (name, format!("{}({})", name, arguments.join(", ")))
+            }
+            None => (symbol.trim(), symbol.trim().to_string()),
+        };
+        let compiles = this._compiles_as(
+            language,
+            format!(
+                r#"{prefix}
+int main(void) {{
+#ifndef {name}
+#ifdef __cplusplus
+    (void) {usage};
+#else
+    (void) {name};
+#endif
+#endif
+    return 0;
+}}"#,
+                prefix = prefix.unwrap_or(""),
+            ),
+            None,
+        );
+        return Ok(required.verify(format!("declaration of `{}`", name), compiles)?);
     }
 
+    /// The arguments the compiler accepts, with `required` an unsupported argument
+    /// is an error.
     fn get_supported_arguments<'v>(
         this: Compiler,
         #[starlark(args)] args: UnpackTuple<Value<'v>>,
+        #[starlark(require = named)] required: Option<Required>,
     ) -> starlark::Result<Vec<Value<'v>>> {
         // https://github.com/mesonbuild/meson/blob/14010f4dfdb9847944592149b189184ab59b6de0/mesonbuild/compilers/mixins/clike.py#L1295
 
+        let required = required.unwrap_or_default();
+        if required.skipped() {
+            return Ok(vec![]);
+        }
         let mut working_args = vec![];
 
         for arg in args.into_iter() {
-            if this._compiles(
+            let supported = this._compiles(
                 r#"extern int i;
                     int i;"#
                     .to_string(),
                 Some(vec!["-c".to_string(), arg.to_str()]),
-            ) {
+            );
+            if required.verify(format!("argument `{}`", arg.to_str()), supported)? {
                 working_args.push(arg);
             }
         }
@@ -256,7 +737,17 @@ int main(void) {{
         #[starlark(require = pos)] type_name: String,
         #[starlark(require = pos)] member_name: String,
         #[starlark(require = named)] prefix: Option<String>,
+        #[starlark(require = named)] required: Option<Required>,
+        eval: &mut Evaluator<'v, '_, '_>,
     ) -> starlark::Result<bool> {
+        record_check(
+            eval,
+            format!("cc.has_member({:?}, {:?})", type_name, member_name),
+        )?;
+        let required = required.unwrap_or_default();
+        if required.skipped() {
+            return Ok(false);
+        }
         let compiles = this._compiles(
             format!(
                 r#"{prefix}
@@ -273,14 +764,25 @@ int main(void) {{
             ),
             None,
         );
-        return Ok(compiles);
+        return Ok(required.verify(
+            format!("member `{}` of `{}`", member_name, type_name),
+            compiles,
+        )?);
     }
 
+    /// The size of `sym` in bytes, or `-1` when it can't be determined like meson does.
     fn sizeof<'v>(
         this: Compiler,
         #[starlark(require = pos)] sym: String,
         #[starlark(require = named)] prefix: Option<String>,
-    ) -> anyhow::Result<u32> {
+        #[starlark(require = named)] required: Option<Required>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> starlark::Result<i32> {
+        record_check(eval, format!("cc.sizeof({:?})", sym))?;
+        let required = required.unwrap_or_default();
+        if required.skipped() {
+            return Ok(-1);
+        }
         let output = this.compile_and_run(format!(
             r#"{prefix}
 #include<stddef.h>
@@ -291,20 +793,29 @@ int main(void) {{
 }}"#,
             sym,
             prefix = prefix.unwrap_or("".into())
-        ))?;
+        ));
 
-        let output = String::from_utf8(output).context("failed to parse stdout")?;
-        output
-            .parse::<u32>()
-            .context(format!("failed to parse int size, {}", output))
+        let size = output.ok().and_then(|output| {
+            let output = String::from_utf8(output).ok()?;
+            output.parse::<i32>().ok()
+        });
+        required.verify(format!("size of `{}`", sym), size.is_some())?;
+        Ok(size.unwrap_or(-1))
     }
 
     fn has_function<'v>(
         this: &Compiler,
         #[starlark(require = pos)] funcname: String,
         #[starlark(require = named)] prefix: Option<String>,
+        #[starlark(require = named)] required: Option<Required>,
         // #[starlark(require = named)] dependencies: Option<Value<'v>>,
+        eval: &mut Evaluator<'v, '_, '_>,
     ) -> anyhow::Result<bool> {
+        record_check(eval, format!("cc.has_function({:?})", funcname))?;
+        let required = required.unwrap_or_default();
+        if required.skipped() {
+            return Ok(false);
+        }
         let prefix = prefix.unwrap_or("".into());
 
         let func = funcname;
@@ -314,11 +825,14 @@ int main(void) {{
         // instead of detecting the stub as a valid symbol.
         // We already included limits.h earlier to ensure that these are defined
         // for stub functions.
-        let stubs_fail = r#"
+        let stubs_fail = format!(
+            r#"
         #if defined __stub_{func} || defined __stub___{func}
         fail fail fail this function is not going to work
         #endif
-        "#;
+        "#,
+            func = func
+        );
 
         // If we have any includes in the prefix supplied by the user, assume
         // that the user wants us to use the symbol prototype defined in those
@@ -342,7 +856,7 @@ int main(void) {{
             )
             .as_str();
 
-            program += stubs_fail;
+            program += stubs_fail.as_str();
 
             // We don't know what the function takes or returns, so return it as an int.
             // Just taking the address or comparing it to void is not enough because
@@ -405,48 +919,241 @@ return {func} ();
         if this._compiles(program, None) {
             return Ok(true);
         } else {
-            let is_builtin = func.starts_with("__builtin_");
-            let no_includes = !prefix.contains("#include");
-            // Detect function as a built-in
-            //
-            // Some functions like alloca() are defined as compiler built-ins which
-            // are inlined by the compiler and you can't take their address, so we
-            // need to look for them differently. On nice compilers like clang, we
-            // can just directly use the __has_builtin() macro.
-            let compiles = this._compiles(
-                format!(
-                    r#"{prefix}
-            int main(void) {{
-
-            /* With some toolchains (MSYS2/mingw for example) the compiler
-             * provides various builtins which are not really implemented and
-             * fall back to the stdlib where they aren't provided and fail at
-             * build/link time. In case the user provides a header, including
-             * the header didn't lead to the function being defined, and the
-             * function we are checking isn't a builtin itself we assume the
-             * builtin is not functional and we just error out. */
-            #if !{no_includes} && !defined({func}) && !{is_builtin}
-                #error "No definition for {__builtin_}{func} found in the prefix"
-            #endif
+            let compiles = this._has_builtin(&func, &prefix);
+            required.verify(format!("function `{}`", func), compiles)
+        }
+    }
 
-            #ifdef __has_builtin
-                #if !__has_builtin({__builtin_}{func})
-                    #error "{__builtin_}{func} not found"
-                #endif
-            #elif ! defined({func})
-                {__builtin_}{func};
-            #endif
-            return 0;
-            }}"#,
-                    func = func,
-                    prefix = prefix,
-                    __builtin_ = if is_builtin { "" } else { "__builtin_" },
-                    is_builtin = std::convert::Into::<i32>::into(is_builtin),
-                    no_includes = std::convert::Into::<i32>::into(no_includes),
+    /// The replacement of `inline` like `AC_C_INLINE`: `False` when `inline` works,
+    /// otherwise `__inline__`, `__inline` or `""` to remove it.
+    /// `args` are added to the compiler flags, e.g. `args = ["-std=c89"]`.
+    fn c_inline<'v>(
+        this: &Compiler,
+        #[starlark(require = named)] args: Option<Either<String, UnpackList<String>>>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<Either<bool, String>> {
+        record_check(eval, "cc.c_inline()".to_string())?;
+        let found = this.c_keyword(
+            &["inline", "__inline__", "__inline"],
+            INLINE_PROGRAM,
+            flag_list(args),
+        );
+        Ok(keyword_define("inline", found, Some("")))
+    }
+
+    /// The replacement of `restrict` like `AC_C_RESTRICT`: `False` when `restrict`
+    /// works, otherwise `__restrict__`, `__restrict`, `_Restrict` or `""` to remove it.
+    /// The nonstandard spellings are tried first as they also work in C++.
+    /// `args` are added to the compiler flags, e.g. `args = ["-std=c89"]`.
+    fn c_restrict<'v>(
+        this: &Compiler,
+        #[starlark(require = named)] args: Option<Either<String, UnpackList<String>>>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<Either<bool, String>> {
+        record_check(eval, "cc.c_restrict()".to_string())?;
+        let found = this.c_keyword(
+            &["__restrict__", "__restrict", "_Restrict", "restrict"],
+            RESTRICT_PROGRAM,
+            flag_list(args),
+        );
+        Ok(keyword_define("restrict", found, Some("")))
+    }
+
+    /// The replacement of `const` like `AC_C_CONST`: `False` when `const` works,
+    /// otherwise `""` to remove it.
+    /// `args` are added to the compiler flags, e.g. `args = ["-std=c89"]`.
+    fn c_const<'v>(
+        this: &Compiler,
+        #[starlark(require = named)] args: Option<Either<String, UnpackList<String>>>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<Either<bool, String>> {
+        record_check(eval, "cc.c_const()".to_string())?;
+        let found = this.c_keyword(&["const"], CONST_PROGRAM, flag_list(args));
+        Ok(keyword_define("const", found, Some("")))
+    }
+
+    /// The replacement of `volatile` like `AC_C_VOLATILE`: `False` when `volatile`
+    /// works, otherwise `""` to remove it.
+    /// `args` are added to the compiler flags, e.g. `args = ["-std=c89"]`.
+    fn c_volatile<'v>(
+        this: &Compiler,
+        #[starlark(require = named)] args: Option<Either<String, UnpackList<String>>>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<Either<bool, String>> {
+        record_check(eval, "cc.c_volatile()".to_string())?;
+        let found = this.c_keyword(&["volatile"], VOLATILE_PROGRAM, flag_list(args));
+        Ok(keyword_define("volatile", found, Some("")))
+    }
+
+    /// The replacement of `typeof` like `AC_C_TYPEOF`: `False` when `typeof` works or
+    /// no spelling does, otherwise `__typeof__`.
+    /// `args` are added to the compiler flags, e.g. `args = ["-std=c89"]`.
+    fn c_typeof<'v>(
+        this: &Compiler,
+        #[starlark(require = named)] args: Option<Either<String, UnpackList<String>>>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<Either<bool, String>> {
+        record_check(eval, "cc.c_typeof()".to_string())?;
+        let found = this.c_keyword(&["typeof", "__typeof__"], TYPEOF_PROGRAM, flag_list(args));
+        Ok(keyword_define("typeof", found, None))
+    }
+
+    /// The replacement of `type_name` like autoconf's `AC_TYPE_*` macros: `False` when
+    /// the type exists once `prefix` is included, by default `stddef.h`, `stdint.h` and
+    /// `sys/types.h`, otherwise the first of `candidates` that exists.
+    ///
+    /// Fixed width, pointer sized and maximum width integers such as `uint64_t` are
+    /// replaced by a builtin type of the same size and signedness, which `candidates`
+    /// defaults to. Without `candidates` the other types are replaced by what autoconf
+    /// uses, e.g. `unsigned int` for `size_t` and `long int` for `off_t`.
+    fn type_fallback<'v>(
+        this: &Compiler,
+        #[starlark(require = pos)] type_name: &str,
+        #[starlark(require = named)] candidates: Option<UnpackList<String>>,
+        #[starlark(require = named)] prefix: Option<&str>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<Either<bool, String>> {
+        record_check(eval, format!("cc.type_fallback({:?})", type_name))?;
+        let prefix = prefix.unwrap_or(TYPE_INCLUDES);
+        if this._has_type(type_name, prefix, None) {
+            return Ok(Either::Left(false));
+        }
+
+        let integer = integer_type(type_name);
+        let candidates = match (candidates, &integer) {
+            (Some(candidates), _) => candidates.items,
+            (None, Some((_, signed))) => integer_candidates(type_name, *signed),
+            (None, None) => match fixed_fallback(type_name) {
+                Some(fallback) => return Ok(Either::Right(fallback.to_string())),
+                None => anyhow::bail!("no fallback is known for `{}`, pass candidates", type_name),
+            },
+        };
+        for candidate in &candidates {
+            let fits = match &integer {
+                Some((size, signed)) => this._compiles(
+                    format!(
+                        r#"
+{prefix}
+int main(void) {{
+    static char size_check[sizeof({candidate}) == {size} ? 1 : -1];
+    static char sign_check[((({candidate}) -1 < 0) == {signed}) ? 1 : -1];
+    return size_check[0] + sign_check[0];
+}}"#,
+                        signed = i32::from(*signed),
+                    ),
+                    None,
                 ),
-                None,
-            );
-            Ok(compiles)
+                None => this._has_type(candidate, prefix, None),
+            };
+            if fits {
+                return Ok(Either::Right(candidate.clone()));
+            }
+        }
+        anyhow::bail!(
+            "none of {} can replace `{}`",
+            candidates.join(", "),
+            type_name
+        )
+    }
+
+    // https://mesonbuild.com/Reference-manual_returned_compiler.html#compilerhas_function_attribute
+    /// Whether the compiler supports the function attribute `name`, e.g. `constructor`
+    /// or `visibility:hidden`, see meson's list of attributes. Warnings about ignored
+    /// attributes are errors so that unsupported ones are not reported as available.
+    /// Unknown names are errors even when `required` is disabled.
+    fn has_function_attribute<'v>(
+        this: &Compiler,
+        #[starlark(require = pos)] name: &str,
+        #[starlark(require = named)] required: Option<Required>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> starlark::Result<bool> {
+        record_check(eval, format!("cc.has_function_attribute({:?})", name))?;
+        let Some((_, program)) = FUNCTION_ATTRIBUTES.iter().find(|(n, _)| *n == name) else {
+            return Err(anyhow::anyhow!("unknown function attribute `{}`", name).into());
+        };
+        let required = required.unwrap_or_default();
+        if required.skipped() {
+            return Ok(false);
         }
+        let compiles = this._compiles_as(
+            "c",
+            program.to_string(),
+            Some(vec!["-Werror=attributes".to_string()]),
+        );
+        Ok(required.verify(format!("function attribute `{}`", name), compiles)?)
+    }
+
+    /// Whether `name` is a compiler builtin such as `__builtin_expect`, using
+    /// `__has_builtin` when the compiler has it like `has_function` does for builtins.
+    fn has_builtin<'v>(
+        this: &Compiler,
+        #[starlark(require = pos)] name: &str,
+        #[starlark(require = named)] prefix: Option<&str>,
+        #[starlark(require = named)] required: Option<Required>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> starlark::Result<bool> {
+        record_check(eval, format!("cc.has_builtin({:?})", name))?;
+        let required = required.unwrap_or_default();
+        if required.skipped() {
+            return Ok(false);
+        }
+        let compiles = this._has_builtin(name, prefix.unwrap_or(""));
+        Ok(required.verify(format!("builtin `{}`", name), compiles)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_types() {
+        assert_eq!(integer_type("int8_t"), Some(("1".to_string(), true)));
+        assert_eq!(integer_type("uint16_t"), Some(("2".to_string(), false)));
+        assert_eq!(integer_type("int32_t"), Some(("4".to_string(), true)));
+        assert_eq!(integer_type("uint64_t"), Some(("8".to_string(), false)));
+        assert_eq!(
+            integer_type("uintptr_t"),
+            Some(("sizeof(void *)".to_string(), false))
+        );
+        assert_eq!(
+            integer_type("intmax_t"),
+            Some(("sizeof(long long int)".to_string(), true))
+        );
+        assert_eq!(integer_type("size_t"), None);
+        assert_eq!(integer_type("uint128_t"), None);
+    }
+
+    #[test]
+    fn integer_candidates_order() {
+        assert_eq!(integer_candidates("int32_t", true)[0], "signed char");
+        assert_eq!(integer_candidates("uint64_t", false)[0], "unsigned char");
+        assert_eq!(
+            integer_candidates("intmax_t", true),
+            [
+                "long long int",
+                "signed char",
+                "short int",
+                "int",
+                "long int"
+            ]
+        );
+        assert_eq!(
+            integer_candidates("uintmax_t", false)[..2],
+            ["unsigned long long int", "unsigned char"]
+        );
+    }
+
+    #[test]
+    fn fixed_fallbacks() {
+        assert_eq!(fixed_fallback("size_t"), Some("unsigned int"));
+        assert_eq!(fixed_fallback("ssize_t"), Some("int"));
+        assert_eq!(fixed_fallback("pid_t"), Some("int"));
+        assert_eq!(fixed_fallback("mode_t"), Some("int"));
+        assert_eq!(fixed_fallback("uid_t"), Some("int"));
+        assert_eq!(fixed_fallback("gid_t"), Some("int"));
+        assert_eq!(fixed_fallback("off_t"), Some("long int"));
+        assert_eq!(fixed_fallback("int64_t"), None);
+        assert_eq!(fixed_fallback("time_t"), None);
     }
 }
@@ -1,16 +1,41 @@
-use starlark::{collections::SmallMap, environment::GlobalsBuilder, starlark_module};
+use starlark::{
+    collections::SmallMap,
+    environment::GlobalsBuilder,
+    eval::Evaluator,
+    starlark_module,
+    values::{dict::DictRef, Value, ValueError},
+};
 
-use super::value::{FrozenCD, CD};
+use crate::values::provenance::provenance;
+
+use super::value::{FrozenCD, ValueAndDescription, CD};
 
 #[starlark_module]
 pub fn register_toplevels(globals: &mut GlobalsBuilder) {
-    #[starlark(
-        as_type = FrozenCD,
-        speculative_exec_safe,
-    )]
-    fn configuration_data<'v>() -> starlark::Result<CD<'v>> {
-        Ok(CD {
-            content: SmallMap::new(),
-        })
+    /// Creates a `configuration_data`, optionally set from the keys and values of a dict.
+    #[starlark(as_type = FrozenCD)]
+    fn configuration_data<'v>(
+        #[starlark(require = pos)] initial: Option<DictRef<'v>>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> starlark::Result<CD<'v>> {
+        let mut content = SmallMap::new();
+        if let Some(initial) = initial {
+            let provenance = provenance(eval)?;
+            for (key, value) in initial.iter() {
+                let key = key
+                    .unpack_str()
+                    .ok_or_else(|| ValueError::IncorrectParameterTypeNamed("initial".to_owned()))?;
+                content.insert(
+                    key.to_string(),
+                    ValueAndDescription {
+                        value,
+                        description: Value::new_none(),
+                        quoted: false,
+                        provenance: provenance.clone(),
+                    },
+                );
+            }
+        }
+        Ok(CD { content })
     }
 }
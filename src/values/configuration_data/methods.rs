@@ -2,12 +2,33 @@ use starlark::{
     environment::MethodsBuilder,
     eval::Evaluator,
     starlark_module,
-    values::{none::NoneType, Value},
+    values::{none::NoneType, StringValue, Value},
 };
 
 use crate::values::configuration_data::value::ValueAndDescription;
+use crate::values::configure_file::literal;
+use crate::values::provenance::provenance;
 
-use super::refs::CDMut;
+use super::refs::{CDMut, CDRef};
+use super::value::CD;
+
+fn unpack<'v>(value: Value<'v>) -> anyhow::Result<CDRef<'v>> {
+    CDRef::from_value(value)
+        .ok_or_else(|| anyhow::anyhow!("expected configuration_data, got {}", value.get_type()))
+}
+
+/// Returns the value of `key`, or `default` when it is not set.
+fn lookup<'v>(
+    this: Value<'v>,
+    key: &str,
+    default: Option<Value<'v>>,
+) -> anyhow::Result<Result<ValueAndDescription<'v>, Value<'v>>> {
+    match (unpack(this)?.content.get(key), default) {
+        (Some(v), _) => Ok(Ok(v.clone())),
+        (None, Some(default)) => Ok(Err(default)),
+        (None, None) => anyhow::bail!("configuration_data has no key `{}`", key),
+    }
+}
 
 #[starlark_module]
 pub(crate) fn configuration_data_methods(registry: &mut MethodsBuilder) {
@@ -16,11 +37,18 @@ pub(crate) fn configuration_data_methods(registry: &mut MethodsBuilder) {
         #[starlark(require = pos)] key: String,
         #[starlark(require = pos)] value: Value<'v>,
         #[starlark(require = named)] description: Option<Value<'v>>,
+        eval: &mut Evaluator<'v, '_, '_>,
     ) -> starlark::Result<NoneType> {
         let mut this = CDMut::from_value(this)?;
-        this.aref
-            .content
-            .insert(key, ValueAndDescription { description, value });
+        this.aref.content.insert(
+            key,
+            ValueAndDescription {
+                description: description.unwrap_or_else(Value::new_none),
+                value,
+                quoted: false,
+                provenance: provenance(eval)?,
+            },
+        );
         Ok(NoneType)
     }
     fn set10<'v>(
@@ -35,7 +63,93 @@ pub(crate) fn configuration_data_methods(registry: &mut MethodsBuilder) {
         let value = eval.heap().alloc(i32::from(value.to_bool()));
         this.aref.content.insert(
             key.to_str().to_string(),
-            ValueAndDescription { value, description },
+            ValueAndDescription {
+                value,
+                description: description.unwrap_or_else(Value::new_none),
+                quoted: false,
+                provenance: provenance(eval)?,
+            },
+        );
+        Ok(NoneType)
+    }
+    /// Returns the value of `key`, or `default` when it is not set. Values set with
+    /// `set_quoted` are returned as the C string literal, like meson does.
+    fn get<'v>(
+        this: Value<'v>,
+        #[starlark(require = pos)] key: &str,
+        #[starlark(require = pos)] default: Option<Value<'v>>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<Value<'v>> {
+        Ok(match lookup(this, key, default)? {
+            Ok(v) if v.quoted => {
+                let literal = literal::c_string(v.value.unpack_str().unwrap_or_default());
+                eval.heap().alloc(literal)
+            }
+            Ok(v) => v.value,
+            Err(default) => default,
+        })
+    }
+    /// Returns the value of `key` like `get`, without the quotes added by `set_quoted`.
+    fn get_unquoted<'v>(
+        this: Value<'v>,
+        #[starlark(require = pos)] key: &str,
+        #[starlark(require = pos)] default: Option<Value<'v>>,
+    ) -> anyhow::Result<Value<'v>> {
+        Ok(match lookup(this, key, default)? {
+            Ok(v) => v.value,
+            Err(default) => default,
+        })
+    }
+    fn has<'v>(this: Value<'v>, #[starlark(require = pos)] key: &str) -> anyhow::Result<bool> {
+        Ok(unpack(this)?.content.contains_key(key))
+    }
+    /// Returns the keys in the order they were first set.
+    fn keys<'v>(this: Value<'v>) -> anyhow::Result<Vec<String>> {
+        Ok(unpack(this)?.content.keys().cloned().collect())
+    }
+    /// Returns a mutable copy, e.g. of a frozen `configuration_data` loaded from another
+    /// module, which only supports reading.
+    fn copy<'v>(this: Value<'v>) -> anyhow::Result<CD<'v>> {
+        Ok(CD {
+            content: unpack(this)?.content.clone(),
+        })
+    }
+    /// Sets every key of `other` in this object, replacing the values already set.
+    fn merge_from<'v>(
+        this: Value<'v>,
+        #[starlark(require = pos)] other: Value<'v>,
+    ) -> anyhow::Result<NoneType> {
+        let content = unpack(other)?.content.clone();
+        let mut this = CDMut::from_value(this)?;
+        this.aref.content.extend(content);
+        Ok(NoneType)
+    }
+    /// Removes `key`, doing nothing when it is not set.
+    fn delete<'v>(
+        this: Value<'v>,
+        #[starlark(require = pos)] key: &str,
+    ) -> anyhow::Result<NoneType> {
+        let mut this = CDMut::from_value(this)?;
+        this.aref.content.shift_remove(key);
+        Ok(NoneType)
+    }
+    /// Sets `key` to a string written as a C string literal, e.g. `"C:\\dir"` for `C:\dir`.
+    fn set_quoted<'v>(
+        this: Value<'v>,
+        #[starlark(require = pos)] key: String,
+        #[starlark(require = pos)] value: StringValue<'v>,
+        #[starlark(require = named)] description: Option<Value<'v>>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> starlark::Result<NoneType> {
+        let mut this = CDMut::from_value(this)?;
+        this.aref.content.insert(
+            key,
+            ValueAndDescription {
+                value: value.to_value(),
+                description: description.unwrap_or_else(Value::new_none),
+                quoted: true,
+                provenance: provenance(eval)?,
+            },
         );
         Ok(NoneType)
     }
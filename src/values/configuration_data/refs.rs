@@ -39,7 +39,7 @@ pub struct CDMut<'v> {
     pub(crate) aref: RefMut<'v, CD<'v>>,
 }
 
-/// Reference to frozen `CData`.
+// Reference to frozen `CData`.
 // pub struct FrozenCDRef {
 //     CData: &'static FrozenCDData,
 // }
@@ -15,7 +15,6 @@ use starlark::values::AllocFrozenValue;
 use starlark::values::AllocValue;
 use starlark::values::Coerce;
 use starlark::values::Freeze;
-use starlark::values::FreezeError;
 use starlark::values::FreezeResult;
 use starlark::values::Freezer;
 use starlark::values::FrozenHeap;
@@ -59,27 +58,57 @@ where
     fn get_methods() -> Option<&'static Methods> {
         configuration_data_methods()
     }
+
+    fn length(&self) -> starlark::Result<i32> {
+        Ok(self.0.len() as i32)
+    }
+
+    fn is_in(&self, other: Value<'v>) -> starlark::Result<bool> {
+        Ok(other
+            .unpack_str()
+            .is_some_and(|key| self.0.contains_key(key)))
+    }
+
+    fn iterate_collect(&self, heap: &'v Heap) -> starlark::Result<Vec<Value<'v>>> {
+        Ok(self.0.alloc_keys(heap))
+    }
 }
 
 pub(crate) type FrozenCD = CDGen<FrozenCDData>;
 // pub(crate) type MutableCD<'v> = CDGen<RefCell<CD<'v>>>;
 
-// Can convert from unfrozen to frozen.
-unsafe impl<'v> Coerce<CD<'v>> for FrozenCDData {}
+/// A value of a `configuration_data`, `V` is `Value` or `FrozenValue`.
+#[derive(Clone, Default, Trace, Debug, ProvidesStaticType, Allocative, Coerce)]
+#[repr(C)]
+pub(crate) struct ValueAndDescriptionGen<V> {
+    /// `None` when there is no description, `Option` does not coerce.
+    pub description: V,
+    pub value: V,
+    /// Set with `set_quoted`, the string is written as a C string literal.
+    pub quoted: bool,
+    /// Where the value was set and the check it comes from, see `provenance`.
+    pub provenance: Option<String>,
+}
+
+pub(crate) type ValueAndDescription<'v> = ValueAndDescriptionGen<Value<'v>>;
 
-#[derive(Clone, Default, Trace, Debug, ProvidesStaticType, Allocative)]
-pub(crate) struct ValueAndDescription<'v> {
-    pub description: Option<Value<'v>>,
-    pub value: Value<'v>,
+impl<'v> ValueAndDescription<'v> {
+    pub(crate) fn description(&self) -> Option<Value<'v>> {
+        Some(self.description).filter(|d| !d.is_none())
+    }
 }
+pub(crate) type FrozenValueAndDescription = ValueAndDescriptionGen<FrozenValue>;
 
-/// Unfrozen CD
-#[derive(Clone, Default, Trace, Debug, ProvidesStaticType, Allocative)]
+/// Content of a `configuration_data`, the frozen one coerces to the unfrozen one.
+#[derive(Clone, Default, Trace, Debug, ProvidesStaticType, Allocative, Coerce)]
 #[repr(transparent)]
-pub struct CD<'v> {
-    pub(crate) content: SmallMap<String, ValueAndDescription<'v>>,
+pub struct CDDataGen<V> {
+    pub(crate) content: SmallMap<String, ValueAndDescriptionGen<V>>,
 }
 
+/// Unfrozen CD
+pub type CD<'v> = CDDataGen<Value<'v>>;
+
 impl<'v> Display for CD<'v> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "configuration_data")
@@ -99,17 +128,61 @@ impl<'v> AllocValue<'v> for CD<'v> {
     }
 }
 
-trait CDLike<'v>: Debug + Allocative {}
+trait CDLike<'v>: Debug + Allocative {
+    fn len(&self) -> usize;
 
-impl<'v> CDLike<'v> for RefCell<CD<'v>> {}
+    fn contains_key(&self, key: &str) -> bool;
 
-// Frozen CDData
-#[derive(Clone, Default, Debug, ProvidesStaticType, Allocative)]
-pub(crate) struct FrozenCDData {
-    pub(crate) content: SmallMap<FrozenValue, FrozenValue>,
+    /// Keys in insertion order.
+    fn alloc_keys(&self, heap: &'v Heap) -> Vec<Value<'v>>;
 }
 
-impl<'v> CDLike<'v> for FrozenCDData {}
+impl<'v> CDLike<'v> for RefCell<CD<'v>> {
+    fn len(&self) -> usize {
+        self.borrow().content.len()
+    }
+
+    fn contains_key(&self, key: &str) -> bool {
+        self.borrow().content.contains_key(key)
+    }
+
+    fn alloc_keys(&self, heap: &'v Heap) -> Vec<Value<'v>> {
+        let cd = self.borrow();
+        cd.content.keys().map(|k| heap.alloc(k.as_str())).collect()
+    }
+}
+
+impl<'v> Freeze for ValueAndDescription<'v> {
+    type Frozen = FrozenValueAndDescription;
+    fn freeze(self, freezer: &Freezer) -> FreezeResult<Self::Frozen> {
+        Ok(FrozenValueAndDescription {
+            description: self.description.freeze(freezer)?,
+            value: self.value.freeze(freezer)?,
+            quoted: self.quoted,
+            provenance: self.provenance,
+        })
+    }
+}
+
+// Frozen CDData
+pub(crate) type FrozenCDData = CDDataGen<FrozenValue>;
+
+impl<'v> CDLike<'v> for FrozenCDData {
+    fn len(&self) -> usize {
+        self.content.len()
+    }
+
+    fn contains_key(&self, key: &str) -> bool {
+        self.content.contains_key(key)
+    }
+
+    fn alloc_keys(&self, heap: &'v Heap) -> Vec<Value<'v>> {
+        self.content
+            .keys()
+            .map(|k| heap.alloc(k.as_str()))
+            .collect()
+    }
+}
 
 impl StarlarkTypeRepr for FrozenCDData {
     type Canonical = Self;
@@ -128,11 +201,8 @@ impl AllocFrozenValue for FrozenCDData {
 // Freeze implementation for CD
 impl<'v> Freeze for CDGen<RefCell<CD<'v>>> {
     type Frozen = CDGen<FrozenCDData>;
-    fn freeze(self, _freezer: &Freezer) -> FreezeResult<Self::Frozen> {
-        // let content = self.0.into_inner().content.freeze(freezer)?;
-        // Ok(CDGen(FrozenCDData { content }))
-        Err(FreezeError::new(
-            "configuration_data can not passed between threads safely.".to_string(),
-        ))
+    fn freeze(self, freezer: &Freezer) -> FreezeResult<Self::Frozen> {
+        let content = self.0.into_inner().content.freeze(freezer)?;
+        Ok(CDGen(FrozenCDData { content }))
     }
 }
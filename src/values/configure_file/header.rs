@@ -0,0 +1,186 @@
+//! Headers generated from `configuration_data` alone, see
+//! https://mesonbuild.com/Reference-manual_functions.html#configure_file
+
+use starlark::collections::SmallMap;
+
+use super::literal;
+use super::template::ConfigValue;
+
+/// A configuration value along with its description.
+#[derive(Debug, Clone)]
+pub(crate) struct Entry {
+    pub(crate) value: ConfigValue,
+    pub(crate) description: Option<String>,
+    /// Set with `set_quoted`, C outputs write the string as a literal.
+    pub(crate) quoted: bool,
+    /// Where the value comes from, only kept when annotating outputs.
+    pub(crate) provenance: Option<String>,
+}
+
+impl Entry {
+    /// The description and provenance written above the value.
+    pub(crate) fn comments(&self) -> Vec<String> {
+        self.description
+            .iter()
+            .chain(self.provenance.iter())
+            .cloned()
+            .collect()
+    }
+
+    /// The value as written to C sources.
+    pub(crate) fn c_value(&self) -> ConfigValue {
+        match &self.value {
+            ConfigValue::String(s) if self.quoted => ConfigValue::String(literal::c_string(s)),
+            value => value.clone(),
+        }
+    }
+}
+
+/// How a generated header guards against multiple inclusion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Guard {
+    None,
+    PragmaOnce,
+    /// `#ifndef NAME` / `#define NAME` / `#endif`.
+    Macro(String),
+}
+
+/// Language of a generated file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum OutputFormat {
+    #[default]
+    C,
+    Nasm,
+    Json,
+    Bzl,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "c" => Ok(Self::C),
+            "nasm" => Ok(Self::Nasm),
+            "json" => Ok(Self::Json),
+            "bzl" => Ok(Self::Bzl),
+            _ => anyhow::bail!(
+                "invalid output_format `{}`, expected c, nasm, json or bzl",
+                s
+            ),
+        }
+    }
+}
+
+/// Emits every entry in `format`, C and NASM defines are written the same way
+/// `#mesondefine` does and preceded by their comments.
+pub(crate) fn generate(
+    entries: &SmallMap<String, Entry>,
+    sort: bool,
+    guard: &Guard,
+    format: OutputFormat,
+) -> anyhow::Result<String> {
+    let mut entries: Vec<(&String, &Entry)> = entries.iter().collect();
+    if sort {
+        entries.sort_by_key(|(key, _)| *key);
+    }
+    match format {
+        OutputFormat::C => Ok(generate_c(&entries, guard)),
+        OutputFormat::Nasm => Ok(generate_nasm(&entries)),
+        OutputFormat::Json => generate_json(&entries),
+        OutputFormat::Bzl => generate_bzl(&entries),
+    }
+}
+
+fn generate_c(entries: &[(&String, &Entry)], guard: &Guard) -> String {
+    let mut out = String::from(
+        "/*\n * Autogenerated by starconf.\n * Do not edit, your changes will be lost.\n */\n\n",
+    );
+    match guard {
+        Guard::None => {}
+        Guard::PragmaOnce => out.push_str("#pragma once\n\n"),
+        Guard::Macro(name) => out.push_str(&format!("#ifndef {}\n#define {}\n\n", name, name)),
+    }
+
+    for (key, entry) in entries {
+        for comment in entry.comments() {
+            out.push_str(&literal::c_comment(&comment));
+            out.push('\n');
+        }
+        out.push_str(&entry.c_value().meson_define(key));
+        out.push_str("\n\n");
+    }
+
+    if let Guard::Macro(name) = guard {
+        out.push_str(&format!("#endif /* {} */\n", name));
+    }
+    out
+}
+
+/// Writes the comments of `entry` one line at a time, each starting with `marker`.
+fn line_comments(out: &mut String, marker: &str, entry: &Entry) {
+    for line in entry.comments().iter().flat_map(|comment| comment.lines()) {
+        out.push_str(format!("{} {}", marker, line).trim_end());
+        out.push('\n');
+    }
+}
+
+fn generate_nasm(entries: &[(&String, &Entry)]) -> String {
+    let mut out =
+        String::from("; Autogenerated by starconf.\n; Do not edit, your changes will be lost.\n\n");
+    for (key, entry) in entries {
+        line_comments(&mut out, ";", entry);
+        let define = match &entry.value {
+            ConfigValue::Bool(true) => format!("%define {}", key),
+            ConfigValue::Bool(false) => format!("%undef {}", key),
+            // only backquoted strings support C escapes in NASM
+            ConfigValue::String(s) if entry.quoted => {
+                let literal = literal::c_string(s).replace('`', "\\`");
+                format!("%define {} `{}`", key, &literal[1..literal.len() - 1])
+            }
+            value => format!("%define {} {}", key, value.substitution()),
+        };
+        out.push_str(&define);
+        out.push_str("\n\n");
+    }
+    out
+}
+
+fn json_value(value: &ConfigValue) -> serde_json::Value {
+    match value {
+        ConfigValue::Bool(b) => (*b).into(),
+        ConfigValue::Int(i) => (*i).into(),
+        ConfigValue::String(s) => s.as_str().into(),
+    }
+}
+
+fn generate_json(entries: &[(&String, &Entry)]) -> anyhow::Result<String> {
+    let object: serde_json::Map<String, serde_json::Value> = entries
+        .iter()
+        .map(|(key, entry)| (key.to_string(), json_value(&entry.value)))
+        .collect();
+    Ok(serde_json::to_string_pretty(&object)? + "\n")
+}
+
+fn generate_bzl(entries: &[(&String, &Entry)]) -> anyhow::Result<String> {
+    let mut out =
+        String::from("# Autogenerated by starconf.\n# Do not edit, your changes will be lost.\n");
+    for (key, entry) in entries {
+        let is_identifier = key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !is_identifier {
+            anyhow::bail!("`{}` is not a valid identifier for a .bzl constant", key)
+        }
+        out.push('\n');
+        line_comments(&mut out, "#", entry);
+        let value = match &entry.value {
+            ConfigValue::Bool(true) => "True".to_string(),
+            ConfigValue::Bool(false) => "False".to_string(),
+            ConfigValue::Int(i) => i.to_string(),
+            // JSON string escapes are valid in Starlark
+            ConfigValue::String(s) => serde_json::to_string(s)?,
+        };
+        out.push_str(&format!("{} = {}\n", key, value));
+    }
+    Ok(out)
+}
@@ -0,0 +1,32 @@
+//! C string literals for values set with `set_quoted` and comments of generated files.
+
+/// Encodes `s` as a C string literal.
+///
+/// Bytes outside of printable ASCII are written as three digit octal escapes, which
+/// unlike hex escapes can't swallow a following digit, and the second `?` of `??` is
+/// escaped so the literal never contains a trigraph.
+pub(crate) fn c_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    let mut prev = None;
+    for b in s.bytes() {
+        match b {
+            b'"' => out.push_str("\\\""),
+            b'\\' => out.push_str("\\\\"),
+            b'\n' => out.push_str("\\n"),
+            b'\t' => out.push_str("\\t"),
+            b'\r' => out.push_str("\\r"),
+            b'?' if prev == Some(b'?') => out.push_str("\\?"),
+            0x20..=0x7e => out.push(char::from(b)),
+            _ => out.push_str(&format!("\\{:03o}", b)),
+        }
+        prev = Some(b);
+    }
+    out.push('"');
+    out
+}
+
+/// Encodes `s` as a C comment, a `*/` in `s` would end the comment early and is
+/// written as `* /`.
+pub(crate) fn c_comment(s: &str) -> String {
+    format!("/* {} */", s.replace("*/", "* /"))
+}
@@ -0,0 +1,285 @@
+use std::cell::RefCell;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use crate::values::configuration_data::CDRef;
+use crate::values::store::Store;
+use anyhow::Context;
+use starlark::collections::SmallMap;
+use starlark::environment::GlobalsBuilder;
+use starlark::eval::Evaluator;
+use starlark::starlark_module;
+use starlark::values::none::NoneOr;
+use starlark::values::none::NoneType;
+use starlark::values::Value;
+use starlark_map::small_set::SmallSet;
+
+mod header;
+pub(crate) mod literal;
+mod template;
+
+use header::Entry;
+use header::Guard;
+use header::OutputFormat;
+use template::ConfigValue;
+use template::Format;
+use template::Template;
+
+#[starlark_module]
+pub fn configure_file_methods(_: &mut GlobalsBuilder) {
+    /// Renders `input` into `output` the way CMake's `configure_file` does,
+    /// `#cmakedefine` and `#cmakedefine01` directives may be indented after the `#`.
+    ///
+    /// `format` selects the variable references that are replaced:
+    /// `cmake` for `@VAR@` and `${VAR}`, `cmake@` for `@VAR@` only, and `meson` for
+    /// `@VAR@` only with `#mesondefine` directives instead of `#cmakedefine`.
+    /// A reference is escaped with a backslash, e.g. `\@VAR@` is written as `@VAR@`,
+    /// and with `meson` `@@` is written as `@`.
+    ///
+    /// `autoconf` rewrites the `#undef NAME` lines of autoheader templates to
+    /// `#define NAME value` or `/* #undef NAME */` like `config.status` does.
+    /// Without `format` it is picked from the directives found in `input`, `autoconf`
+    /// is only picked for templates without `@VAR@` or `${VAR}` references.
+    ///
+    /// When `input` is `None` a header defining every key is generated instead, in
+    /// insertion order or alphabetically with `sort`, with descriptions as comments.
+    /// It is guarded by `#ifndef include_guard` or by `#pragma once` with `pragma_once`.
+    /// `output_format` generates a `c` header, a `nasm` include of `%define`s, a `json`
+    /// object or a `bzl` file of constants instead.
+    ///
+    /// Descriptions of `configuration` are written as comments above the defines, with
+    /// `provenance` the location that set each value and the check it comes from are
+    /// too, e.g. `/* cc.has_header("sys/acl.h") at config.star:162 */`.
+    ///
+    /// With `strict`, which defaults to the `--strict` flag, keys used by `input` but
+    /// missing from `configuration` and keys of `configuration` never used by `input`
+    /// are errors instead of being left undefined or ignored.
+    #[allow(clippy::too_many_arguments)]
+    fn configure_file<'v>(
+        #[starlark(require = named)] input: NoneOr<&str>,
+        #[starlark(require = named)] output: Value<'v>,
+        #[starlark(require = named)] configuration: Value<'v>,
+        #[starlark(require = named)] format: Option<&str>,
+        #[starlark(require = named, default = false)] sort: bool,
+        #[starlark(require = named)] include_guard: Option<&str>,
+        #[starlark(require = named, default = false)] pragma_once: bool,
+        #[starlark(require = named)] output_format: Option<&str>,
+        #[starlark(require = named)] strict: Option<bool>,
+        #[starlark(require = named, default = false)] provenance: bool,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<NoneType> {
+        let store = Store::get(eval)?;
+        let strict = strict.unwrap_or(store.strict);
+        let configuration =
+            CDRef::from_value(configuration).context("failed to unpack configuration")?;
+
+        let mut entries = SmallMap::new();
+        for (key, v) in configuration.content.iter() {
+            let value = ConfigValue::from_value(v.value).with_context(|| {
+                format!(
+                    "configuration_data {} has unsupported type {}",
+                    key,
+                    v.value.get_type()
+                )
+            })?;
+            let description = v.description().map(|d| d.to_str());
+            entries.insert(
+                key.clone(),
+                Entry {
+                    value,
+                    description,
+                    quoted: v.quoted,
+                    provenance: v.provenance.clone().filter(|_| provenance),
+                },
+            );
+        }
+
+        let content = match input {
+            NoneOr::Other(input) => {
+                if sort || include_guard.is_some() || pragma_once || output_format.is_some() {
+                    anyhow::bail!(
+                        "sort, include_guard, pragma_once and output_format are only supported without input"
+                    )
+                }
+                let template =
+                    fs::read_to_string(input).context("failed to read the input file")?;
+                let format = match format {
+                    Some(format) => format.parse()?,
+                    None => Format::detect(&template),
+                };
+                let used = RefCell::new(SmallSet::new());
+                let rendered = Template::parse(&template, format)?.render(
+                    &|key| {
+                        used.borrow_mut().insert(key.to_string());
+                        entries.get(key).map(Entry::c_value)
+                    },
+                    &|key| entries.get(key).map(Entry::comments).unwrap_or_default(),
+                );
+                let used = used.into_inner();
+                let unused: Vec<&String> = entries
+                    .keys()
+                    .filter(|key| !used.contains(key.as_str()))
+                    .collect();
+
+                if let Some(reports) = &store.coverage {
+                    let set: Vec<&String> = used
+                        .iter()
+                        .filter(|key| entries.contains_key(*key))
+                        .collect();
+                    let undef: Vec<serde_json::Value> = rendered
+                        .missing
+                        .iter()
+                        .map(|(line, key)| serde_json::json!({"key": key, "line": line}))
+                        .collect();
+                    reports.borrow_mut().push(serde_json::json!({
+                        "input": input,
+                        "set": set,
+                        "undef": undef,
+                        "unused": unused,
+                    }));
+                    return Ok(NoneType);
+                }
+
+                if strict {
+                    let mut errors: Vec<String> = rendered
+                        .missing
+                        .iter()
+                        .map(|(line, key)| format!("{}:{}: {} is not set", input, line, key))
+                        .collect();
+                    errors.extend(
+                        unused
+                            .iter()
+                            .map(|key| format!("{} is set but never used by {}", key, input)),
+                    );
+                    if !errors.is_empty() {
+                        anyhow::bail!(
+                            "configuration_data does not match {}:\n  {}",
+                            input,
+                            errors.join("\n  ")
+                        )
+                    }
+                }
+                for (_, key) in &rendered.missing {
+                    println!("missing configuration_data {}", key);
+                }
+                rendered.content
+            }
+            NoneOr::None => {
+                let output_format: OutputFormat = output_format.unwrap_or("c").parse()?;
+                if output_format != OutputFormat::C && (include_guard.is_some() || pragma_once) {
+                    anyhow::bail!("include_guard and pragma_once are only supported for C")
+                }
+                let guard = match (include_guard, pragma_once) {
+                    (Some(_), true) => {
+                        anyhow::bail!("include_guard and pragma_once are mutually exclusive")
+                    }
+                    (Some(name), false) => Guard::Macro(name.to_string()),
+                    (None, true) => Guard::PragmaOnce,
+                    (None, false) => Guard::None,
+                };
+                if let Some(reports) = &store.coverage {
+                    reports.borrow_mut().push(serde_json::json!({
+                        "input": null,
+                        "set": entries.keys().collect::<Vec<_>>(),
+                        "undef": [],
+                        "unused": [],
+                    }));
+                    return Ok(NoneType);
+                }
+                header::generate(&entries, sort, &guard, output_format)?
+            }
+        };
+
+        write_if_changed(output.to_str().as_str(), &content)
+            .map(|_| NoneType)
+            .context("failed to write configuration file")
+    }
+}
+
+/// Writes `content` to a temporary file next to `path` and renames it over `path`, so
+/// a failure never leaves a partial file, unless `path` already has that content and
+/// would only cause rebuilds.
+pub(crate) fn write_if_changed(path: &str, content: &str) -> anyhow::Result<()> {
+    if fs::read(path).is_ok_and(|existing| existing == content.as_bytes()) {
+        eprintln!("{} is unchanged", path);
+        return Ok(());
+    }
+
+    let dir = Path::new(path)
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let mut file = tempfile::Builder::new()
+        .prefix(".starconf")
+        .tempfile_in(dir)
+        .with_context(|| format!("failed to create a temporary file in {}", dir.display()))?;
+    file.write_all(content.as_bytes())?;
+    // temporary files are only readable by their owner, keep the permissions of the
+    // replaced file
+    match fs::metadata(path) {
+        Ok(existing) => file.as_file().set_permissions(existing.permissions())?,
+        #[cfg(unix)]
+        Err(_) => {
+            use std::os::unix::fs::PermissionsExt;
+            file.as_file()
+                .set_permissions(fs::Permissions::from_mode(0o644))?
+        }
+        #[cfg(not(unix))]
+        Err(_) => {}
+    }
+    file.persist(path)
+        .with_context(|| format!("failed to rename the temporary file to {}", path))?;
+    eprintln!("wrote {}", path);
+    Ok(())
+}
+pub fn register_toplevels(globals: &mut GlobalsBuilder) {
+    configure_file_methods(globals);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::*;
+
+    #[test]
+    fn unchanged_content_keeps_mtime() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.h");
+        fs::write(&path, "#define A 1\n").unwrap();
+        let past = SystemTime::now() - Duration::from_secs(3600);
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(past)
+            .unwrap();
+
+        write_if_changed(path.to_str().unwrap(), "#define A 1\n").unwrap();
+        assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), past);
+
+        write_if_changed(path.to_str().unwrap(), "#define A 2\n").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "#define A 2\n");
+        assert_ne!(fs::metadata(&path).unwrap().modified().unwrap(), past);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+
+        let new = dir.path().join("new.h");
+        write_if_changed(new.to_str().unwrap(), "").unwrap();
+        assert_eq!(mode(&new), 0o644);
+
+        let existing = dir.path().join("existing.h");
+        fs::write(&existing, "old").unwrap();
+        fs::set_permissions(&existing, fs::Permissions::from_mode(0o600)).unwrap();
+        write_if_changed(existing.to_str().unwrap(), "new").unwrap();
+        assert_eq!(mode(&existing), 0o600);
+    }
+}
@@ -0,0 +1,510 @@
+//! Parsing and rendering of `configure_file` templates, see
+//! https://cmake.org/cmake/help/latest/command/configure_file.html
+
+use starlark::values::UnpackValue;
+use starlark::values::Value;
+
+use super::literal;
+
+/// A configuration value as seen by templates.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ConfigValue {
+    Bool(bool),
+    Int(i64),
+    String(String),
+}
+
+impl ConfigValue {
+    pub(crate) fn from_value(value: Value) -> Option<Self> {
+        if let Some(b) = value.unpack_bool() {
+            Some(Self::Bool(b))
+        } else if let Some(i) = i64::unpack_value(value).ok().flatten() {
+            Some(Self::Int(i))
+        } else {
+            value.unpack_str().map(|s| Self::String(s.to_string()))
+        }
+    }
+
+    /// Whether CMake considers the value false, see
+    /// https://cmake.org/cmake/help/latest/command/if.html#basic-expressions
+    pub(crate) fn is_off(&self) -> bool {
+        match self {
+            Self::Bool(b) => !b,
+            Self::Int(i) => *i == 0,
+            Self::String(s) => {
+                let upper = s.to_ascii_uppercase();
+                matches!(
+                    upper.as_str(),
+                    "" | "0" | "OFF" | "NO" | "FALSE" | "N" | "IGNORE" | "NOTFOUND"
+                ) || upper.ends_with("-NOTFOUND")
+            }
+        }
+    }
+
+    /// Value of a `#define` written by autoconf's `config.status`, `None` leaves the
+    /// macro undefined.
+    fn autoconf(&self) -> Option<String> {
+        match self {
+            Self::Bool(false) => None,
+            Self::Bool(true) => Some("1".to_string()),
+            Self::Int(i) => Some(i.to_string()),
+            Self::String(s) if s.is_empty() => Some("/**/".to_string()),
+            Self::String(s) => Some(s.clone()),
+        }
+    }
+
+    /// The line `#mesondefine NAME` is replaced with.
+    pub(crate) fn meson_define(&self, name: &str) -> String {
+        match self {
+            Self::Bool(true) => format!("#define {}", name),
+            Self::Bool(false) => format!("#undef {}", name),
+            value => format!("#define {} {}", name, value.substitution()),
+        }
+    }
+
+    /// Text replacing a variable reference such as `@VAR@`.
+    pub(crate) fn substitution(&self) -> String {
+        match self {
+            Self::Bool(b) => i32::from(*b).to_string(),
+            Self::Int(i) => i.to_string(),
+            Self::String(s) => s.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Line {
+    Text(String),
+    /// `#cmakedefine NAME ...`, `define` is the line with the directive
+    /// replaced by `#define`, emitted when `NAME` is not off.
+    CmakeDefine {
+        name: String,
+        define: String,
+    },
+    /// `#cmakedefine01 NAME ...`, `define` is the line with the directive
+    /// replaced by `#define`, it is emitted followed by ` 0` or ` 1`.
+    CmakeDefine01 {
+        name: String,
+        define: String,
+    },
+    /// `#mesondefine NAME`.
+    MesonDefine {
+        name: String,
+    },
+    /// `#undef NAME` or `#define NAME ...` of an autoheader template, `prefix` is
+    /// everything up to the `define` or `undef` keyword.
+    Autoconf {
+        name: String,
+        prefix: String,
+        params: String,
+        undef: bool,
+        line: String,
+    },
+}
+
+impl Line {
+    /// The key of a directive.
+    fn name(&self) -> Option<&str> {
+        match self {
+            Line::Text(_) => None,
+            Line::CmakeDefine { name, .. }
+            | Line::CmakeDefine01 { name, .. }
+            | Line::MesonDefine { name }
+            | Line::Autoconf { name, .. } => Some(name),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Template {
+    /// Lines along with their 1-based line number.
+    pub(crate) lines: Vec<(usize, Line)>,
+    format: Format,
+    trailing_newline: bool,
+}
+
+/// Output of rendering a template.
+#[derive(Debug, Default)]
+pub(crate) struct Rendered {
+    pub(crate) content: String,
+    /// Keys referenced by the template but absent from the configuration, with their line number.
+    pub(crate) missing: Vec<(usize, String)>,
+}
+
+/// A `#<indent>keyword<blank>NAME` directive found in a line.
+struct Directive<'a> {
+    indent: &'a str,
+    name: &'a str,
+}
+
+fn is_blank(c: char) -> bool {
+    c == ' ' || c == '\t'
+}
+
+fn is_name(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Finds the first `#` followed by optional blanks, `keyword`, at least one blank and a name,
+/// the same way CMake's unanchored `#([ \t]*)keyword[ \t]+([A-Za-z_0-9]*)` does.
+fn find_directive<'a>(line: &'a str, keyword: &str) -> Option<Directive<'a>> {
+    for (start, _) in line.match_indices('#') {
+        let rest = &line[start + 1..];
+        let indent_len = rest.len() - rest.trim_start_matches(is_blank).len();
+        let Some(after_keyword) = rest[indent_len..].strip_prefix(keyword) else {
+            continue;
+        };
+        let name_start = after_keyword.trim_start_matches(is_blank);
+        if name_start.len() == after_keyword.len() {
+            continue;
+        }
+        let name_len = name_start.len() - name_start.trim_start_matches(is_name).len();
+        return Some(Directive {
+            indent: &rest[..indent_len],
+            name: &name_start[..name_len],
+        });
+    }
+    None
+}
+
+/// Matches the `#define` and `#undef` lines `config.status` rewrites, i.e.
+/// `^[ \t]*#[ \t]*(define|undef)[ \t]+NAME([ \t(]|$)`.
+fn parse_autoconf(line: &str) -> Option<Line> {
+    let rest = line.trim_start_matches(is_blank).strip_prefix('#')?;
+    let rest = rest.trim_start_matches(is_blank);
+    let (undef, after_keyword) = if let Some(after) = rest.strip_prefix("undef") {
+        (true, after)
+    } else {
+        (false, rest.strip_prefix("define")?)
+    };
+    let name_start = after_keyword.trim_start_matches(is_blank);
+    if name_start.len() == after_keyword.len()
+        || !name_start.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+    {
+        return None;
+    }
+    let name_len = name_start.len() - name_start.trim_start_matches(is_name).len();
+    let after_name = &name_start[name_len..];
+    if !(after_name.is_empty() || after_name.starts_with([' ', '\t', '('])) {
+        return None;
+    }
+    // parameters of a function-like macro are kept
+    let params = match after_name.find(')') {
+        Some(end) if !undef && after_name.starts_with('(') => &after_name[..end + 1],
+        _ => "",
+    };
+    Some(Line::Autoconf {
+        name: name_start[..name_len].to_string(),
+        prefix: line[..line.len() - rest.len()].to_string(),
+        params: params.to_string(),
+        undef,
+        line: line.to_string(),
+    })
+}
+
+fn is_mesondefine(line: &str) -> bool {
+    line.trim_start().starts_with("#mesondefine")
+}
+
+/// Syntax of templates, see
+/// https://mesonbuild.com/Reference-manual_functions.html#configure_file_format
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum Format {
+    /// `@VAR@` and `${VAR}` references with `#cmakedefine` directives.
+    #[default]
+    Cmake,
+    /// Only `@VAR@` references with `#cmakedefine` directives.
+    CmakeAt,
+    /// Only `@VAR@` references with `#mesondefine` directives.
+    Meson,
+    /// `#undef` lines of autoheader templates, without variable references.
+    Autoconf,
+}
+
+impl std::str::FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cmake" => Ok(Self::Cmake),
+            "cmake@" => Ok(Self::CmakeAt),
+            "meson" => Ok(Self::Meson),
+            "autoconf" => Ok(Self::Autoconf),
+            _ => anyhow::bail!(
+                "invalid format `{}`, expected cmake, cmake@, meson or autoconf",
+                s
+            ),
+        }
+    }
+}
+
+impl Format {
+    fn is_special(&self, c: char) -> bool {
+        c == '@' || (c == '$' && *self == Self::Cmake)
+    }
+
+    fn has_cmakedefine(&self) -> bool {
+        matches!(self, Self::Cmake | Self::CmakeAt)
+    }
+
+    /// Picks the format from the directives used in `content`, `#cmakedefine` wins
+    /// over `#mesondefine` which wins over autoheader's `#undef`. Templates with
+    /// `@VAR@` or `${VAR}` references are never autoheader ones.
+    pub(crate) fn detect(content: &str) -> Self {
+        let mut format = Self::Cmake;
+        let mut references = false;
+        for line in content.lines() {
+            references = references || has_reference(line);
+            if find_directive(line, "cmakedefine").is_some()
+                || find_directive(line, "cmakedefine01").is_some()
+            {
+                return Self::Cmake;
+            }
+            if is_mesondefine(line) {
+                format = Self::Meson;
+            } else if format != Self::Meson
+                && matches!(
+                    parse_autoconf(line),
+                    Some(Line::Autoconf { undef: true, .. })
+                )
+            {
+                format = Self::Autoconf;
+            }
+        }
+        if format == Self::Autoconf && references {
+            return Self::Cmake;
+        }
+        format
+    }
+}
+
+fn is_var(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Returns the variable name if `rest` starts with `VAR` followed by `end`.
+fn var_name(rest: &str, end: char) -> Option<&str> {
+    let len = rest.len() - rest.trim_start_matches(is_var).len();
+    (len > 0 && rest[len..].starts_with(end)).then(|| &rest[..len])
+}
+
+/// Whether `line` contains a `@VAR@` or `${VAR}` reference.
+fn has_reference(line: &str) -> bool {
+    line.match_indices(['@', '$']).any(|(i, c)| match c {
+        "@" => var_name(&line[i + 1..], '@').is_some(),
+        _ => line[i..]
+            .strip_prefix("${")
+            .is_some_and(|rest| var_name(rest, '}').is_some()),
+    })
+}
+
+/// Replaces variable references the way meson does, unknown variables are
+/// replaced by an empty string and reported in `missing`.
+///
+/// A backslash escapes a reference, pairs of backslashes preceding a
+/// reference are halved, backslashes anywhere else are kept as is. In the
+/// meson format `@@` is written as `@`.
+fn substitute(
+    line: &str,
+    format: Format,
+    lookup: &dyn Fn(&str) -> Option<ConfigValue>,
+    missing: &mut Vec<String>,
+) -> String {
+    let mut out = String::new();
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        if c == '\\' {
+            let run = rest.len() - rest.trim_start_matches('\\').len();
+            let after = &rest[run..];
+            if after.starts_with(|c| format.is_special(c)) {
+                out.push_str(&"\\".repeat(run / 2));
+                rest = after;
+                if run % 2 == 1 {
+                    // the escaped character can't start a reference
+                    out.push_str(&rest[..1]);
+                    rest = &rest[1..];
+                }
+            } else {
+                out.push_str(&rest[..run]);
+                rest = after;
+            }
+            continue;
+        }
+
+        if format == Format::Meson && rest.starts_with("@@") {
+            out.push('@');
+            rest = &rest[2..];
+            continue;
+        }
+
+        let reference = match c {
+            '@' => var_name(&rest[1..], '@').map(|name| (name, name.len() + 2)),
+            '$' if format == Format::Cmake => rest
+                .strip_prefix("${")
+                .and_then(|rest| var_name(rest, '}'))
+                .map(|name| (name, name.len() + 3)),
+            _ => None,
+        };
+        match reference {
+            Some((name, len)) => {
+                match lookup(name) {
+                    Some(value) => out.push_str(&value.substitution()),
+                    None => missing.push(name.to_string()),
+                }
+                rest = &rest[len..];
+            }
+            None => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    out
+}
+
+impl Template {
+    pub(crate) fn parse(content: &str, format: Format) -> anyhow::Result<Self> {
+        let trailing_newline = content.ends_with('\n');
+        let content = content.strip_suffix('\n').unwrap_or(content);
+        let lines = if content.is_empty() && trailing_newline {
+            vec![String::new()]
+        } else if content.is_empty() {
+            vec![]
+        } else {
+            content.split('\n').map(str::to_string).collect()
+        };
+
+        let lines = lines
+            .into_iter()
+            .enumerate()
+            .map(|(i, line)| Ok((i + 1, Self::parse_line(line, format)?)))
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(Self {
+            lines,
+            format,
+            trailing_newline,
+        })
+    }
+
+    fn parse_line(line: String, format: Format) -> anyhow::Result<Line> {
+        if format == Format::Autoconf {
+            return Ok(parse_autoconf(&line).unwrap_or(Line::Text(line)));
+        }
+        if format == Format::Meson {
+            if !is_mesondefine(&line) {
+                return Ok(Line::Text(line));
+            }
+            let tokens: Vec<_> = line.split_whitespace().collect();
+            let [_, name] = tokens[..] else {
+                anyhow::bail!(
+                    "#mesondefine does not contain exactly two tokens: {}",
+                    line.trim()
+                )
+            };
+            return Ok(Line::MesonDefine {
+                name: name.to_string(),
+            });
+        }
+        if !format.has_cmakedefine() {
+            return Ok(Line::Text(line));
+        }
+        Ok(if let Some(d) = find_directive(&line, "cmakedefine") {
+            Line::CmakeDefine {
+                name: d.name.to_string(),
+                define: line.replace(
+                    &format!("#{}cmakedefine", d.indent),
+                    &format!("#{}define", d.indent),
+                ),
+            }
+        } else if let Some(d) = find_directive(&line, "cmakedefine01") {
+            Line::CmakeDefine01 {
+                name: d.name.to_string(),
+                define: line.replace(
+                    &format!("#{}cmakedefine01", d.indent),
+                    &format!("#{}define", d.indent),
+                ),
+            }
+        } else {
+            Line::Text(line)
+        })
+    }
+
+    /// Renders the template, `comments` returns the comments written above the
+    /// directive of a key.
+    pub(crate) fn render(
+        &self,
+        lookup: &dyn Fn(&str) -> Option<ConfigValue>,
+        comments: &dyn Fn(&str) -> Vec<String>,
+    ) -> Rendered {
+        let mut rendered = Rendered::default();
+        let mut lines = vec![];
+        for (line_no, line) in &self.lines {
+            if let Some(name) = line.name() {
+                lines.extend(comments(name).iter().map(|c| literal::c_comment(c)));
+            }
+            let mut missing = vec![];
+            let out = match line {
+                Line::Text(text) if self.format == Format::Autoconf => text.clone(),
+                Line::Text(text) => substitute(text, self.format, lookup, &mut missing),
+                Line::CmakeDefine { name, define } => match lookup(name) {
+                    Some(value) if !value.is_off() => {
+                        substitute(define, self.format, lookup, &mut missing)
+                    }
+                    value => {
+                        if value.is_none() {
+                            missing.push(name.clone());
+                        }
+                        format!("/* #undef {} */", name)
+                    }
+                },
+                Line::CmakeDefine01 { name, define } => {
+                    let value = lookup(name);
+                    if value.is_none() {
+                        missing.push(name.clone());
+                    }
+                    let on = value.is_some_and(|v| !v.is_off());
+                    // like CMake the value is appended after anything following the name
+                    format!(
+                        "{} {}",
+                        substitute(define, self.format, lookup, &mut missing),
+                        i32::from(on)
+                    )
+                }
+                Line::MesonDefine { name } => match lookup(name) {
+                    Some(value) => value.meson_define(name),
+                    None => {
+                        missing.push(name.clone());
+                        format!("/* #undef {} */", name)
+                    }
+                },
+                Line::Autoconf {
+                    name,
+                    prefix,
+                    params,
+                    undef,
+                    line,
+                } => {
+                    let value = lookup(name);
+                    if value.is_none() && *undef {
+                        missing.push(name.clone());
+                    }
+                    match value.and_then(|v| v.autoconf()) {
+                        Some(value) => format!("{}define {}{} {}", prefix, name, params, value),
+                        None if *undef => format!("/* {}undef {} */", prefix, name),
+                        None => line.clone(),
+                    }
+                }
+            };
+            lines.push(out);
+            rendered
+                .missing
+                .extend(missing.into_iter().map(|name| (*line_no, name)));
+        }
+        rendered.content = lines.join("\n");
+        if self.trailing_newline {
+            rendered.content.push('\n');
+        }
+        rendered
+    }
+}
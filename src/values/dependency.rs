@@ -3,13 +3,14 @@ use std::fmt;
 use std::fmt::Display;
 
 use allocative::Allocative;
-use starlark;
+use either::Either;
 use starlark::any::ProvidesStaticType;
 use starlark::environment::GlobalsBuilder;
 use starlark::environment::Methods;
 use starlark::environment::MethodsBuilder;
 use starlark::environment::MethodsStatic;
 use starlark::eval::Evaluator;
+use starlark::values::list::UnpackList;
 use starlark::values::Coerce;
 use starlark::values::FreezeResult;
 use starlark::values::FrozenValue;
@@ -24,6 +25,7 @@ use starlark_derive::Freeze;
 use starlark_derive::NoSerialize;
 use starlark_derive::Trace;
 
+use crate::values::feature::Required;
 use crate::values::store::Store;
 use crate::values::version::Version;
 
@@ -79,10 +81,11 @@ pub(crate) fn dependency_methods(registry: &mut MethodsBuilder) {
         Ok(this.found)
     }
 
-    fn version<'v>(
-        this: Dependency<'v>,
-        eval: &mut Evaluator<'v, '_, '_>,
-    ) -> anyhow::Result<Value<'v>> {
+    fn name<'v>(this: Dependency<'v>) -> anyhow::Result<Value<'v>> {
+        Ok(this.name.to_value())
+    }
+
+    fn version<'v>(this: Dependency<'v>) -> anyhow::Result<Value<'v>> {
         Ok(this.version)
     }
 
@@ -94,44 +97,69 @@ pub(crate) fn dependency_methods(registry: &mut MethodsBuilder) {
         #[starlark(require = named, default = false)] includes: Value<'v>,
         #[starlark(require = named, default = false)] sources: Value<'v>,
     ) -> anyhow::Result<Dependency<'v>> {
+        // dependencies carry no arguments yet, so every part is the whole
+        let _ = (compile_args, link_args, links, includes, sources);
         Ok(this)
     }
 }
 
 #[starlark_module]
 pub(crate) fn register_toplevels(globals: &mut GlobalsBuilder) {
+    /// Looks up a dependency passed with `--dependency name=version`, see
+    /// https://mesonbuild.com/Reference-manual_functions.html#dependency
+    ///
+    /// `version` takes constraints such as `>=1.2`, all of them must match.
     fn dependency<'v>(
-        #[starlark(require = pos)] name: Value<'v>,
+        #[starlark(require = pos)] name: &str,
+        #[starlark(require = named)] version: Option<Either<String, UnpackList<String>>>,
+        #[starlark(require = named)] required: Option<Required>,
         #[starlark(require = named)] disabler: Option<bool>,
-        #[starlark(require = named)] required: Option<Value<'v>>,
         eval: &mut Evaluator<'v, '_, '_>,
     ) -> anyhow::Result<Value<'v>> {
-        let store = eval
-            .extra
-            .unwrap()
-            .downcast_ref::<Store>()
-            .unwrap()
-            .dependencies
-            .borrow();
-        let found_dep = store.iter().find(|dd| dd.name == name.to_str());
-        if found_dep.is_none() && required.is_some_and(|r| r.to_bool()) {
-            anyhow::bail!("missing dependency {}", name.to_string())
-        }
-        if found_dep.is_none() {
+        // a disabler is never returned, not-found dependencies are always objects
+        let _ = disabler;
+        let required = required.unwrap_or_default();
+        let constraints = version
+            .map(|v| v.either(|v| vec![v], |v| v.items))
+            .unwrap_or_default();
+
+        let store = Store::get(eval)?;
+        let dependencies = store.dependencies.borrow();
+        let found_dep = if required.skipped() {
+            None
+        } else {
+            dependencies.iter().find(|dd| dd.name == name)
+        };
+        if found_dep.is_none() && !required.skipped() {
             println!(
                 "missing dependency {}, available: {:?}",
-                name.to_str(),
-                store.iter().map(|d| &d.name).collect::<Vec<&String>>()
+                name,
+                dependencies
+                    .iter()
+                    .map(|d| &d.name)
+                    .collect::<Vec<&String>>()
             )
         }
+        let found_dep = found_dep.filter(|dep| {
+            let matches = constraints.iter().all(|c| dep.version.matches(c));
+            if !matches {
+                println!(
+                    "dependency {} found with version {} but {} is needed",
+                    name,
+                    dep.version,
+                    constraints.join(", ")
+                )
+            }
+            matches
+        });
+        required.verify(format!("dependency `{}`", name), found_dep.is_some())?;
+
         Ok(eval.heap().alloc_complex(Dependency {
-            name: eval.heap().alloc_str(name.to_str().as_str()),
+            name: eval.heap().alloc_str(name),
             found: found_dep.is_some(),
-            version: eval.heap().alloc_simple(
-                found_dep.map_or(Version::new("0.0.0").unwrap(), |dep| {
-                    Version::from(dep.semver.clone())
-                }),
-            ),
+            version: eval
+                .heap()
+                .alloc_simple(found_dep.map_or(Version::new("unknown"), |dep| dep.version.clone())),
         }))
     }
 }
@@ -0,0 +1,171 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::fmt::Display;
+
+use allocative::Allocative;
+use either::Either;
+use starlark::environment::Methods;
+use starlark::environment::MethodsBuilder;
+use starlark::environment::MethodsStatic;
+use starlark::starlark_module;
+use starlark::starlark_simple_value;
+use starlark::typing::Ty;
+use starlark::values::starlark_value;
+use starlark::values::type_repr::StarlarkTypeRepr;
+use starlark::values::NoSerialize;
+use starlark::values::ProvidesStaticType;
+use starlark::values::StarlarkValue;
+use starlark::values::UnpackValue;
+use starlark::values::Value;
+use starlark::values::ValueLike;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Allocative)]
+pub(crate) enum FeatureState {
+    Enabled,
+    Disabled,
+    Auto,
+}
+
+impl std::str::FromStr for FeatureState {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "enabled" => Ok(Self::Enabled),
+            "disabled" => Ok(Self::Disabled),
+            "auto" => Ok(Self::Auto),
+            _ => Err(format!(
+                "invalid feature state '{}', expected enabled, disabled or auto",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for FeatureState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Enabled => write!(f, "enabled"),
+            Self::Disabled => write!(f, "disabled"),
+            Self::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+/// Value of a `feature` option, see https://mesonbuild.com/Build-options.html#features
+#[derive(Clone, Debug, derive_more::Display, ProvidesStaticType, NoSerialize, Allocative)]
+#[display("<feature {}>", state)]
+pub(crate) struct Feature {
+    pub(crate) state: FeatureState,
+}
+
+starlark_simple_value!(Feature);
+
+#[starlark_value(type = "feature")]
+impl<'v> StarlarkValue<'v> for Feature {
+    fn get_methods() -> Option<&'static Methods> {
+        static RES: MethodsStatic = MethodsStatic::new();
+        RES.methods(feature_methods)
+    }
+
+    fn equals(&self, other: Value<'v>) -> starlark::Result<bool> {
+        Ok(other
+            .downcast_ref::<Feature>()
+            .is_some_and(|other| other.state == self.state))
+    }
+}
+
+#[starlark_module]
+fn feature_methods(builder: &mut MethodsBuilder) {
+    /// Returns whether the feature was set to `enabled`.
+    fn enabled(this: &Feature) -> starlark::Result<bool> {
+        Ok(this.state == FeatureState::Enabled)
+    }
+
+    /// Returns whether the feature was set to `disabled`.
+    fn disabled(this: &Feature) -> starlark::Result<bool> {
+        Ok(this.state == FeatureState::Disabled)
+    }
+
+    /// Returns whether the feature was set to `auto`.
+    fn auto(this: &Feature) -> starlark::Result<bool> {
+        Ok(this.state == FeatureState::Auto)
+    }
+
+    /// Returns whether the feature is not `disabled`.
+    fn allowed(this: &Feature) -> starlark::Result<bool> {
+        Ok(this.state != FeatureState::Disabled)
+    }
+
+    /// Returns the feature if `cond` is true, a disabled feature if it was `auto`,
+    /// and fails if the feature was `enabled`.
+    fn require(
+        this: &Feature,
+        #[starlark(require = pos)] cond: bool,
+        #[starlark(require = named, default = "")] error_message: &str,
+    ) -> anyhow::Result<Feature> {
+        if cond {
+            return Ok(this.clone());
+        }
+        if this.state == FeatureState::Enabled {
+            anyhow::bail!(
+                "feature is enabled but its requirement is not met: {}",
+                error_message
+            )
+        }
+        Ok(Feature {
+            state: FeatureState::Disabled,
+        })
+    }
+}
+
+/// The `required` argument of checks, either a `bool` or a `feature`.
+///
+/// A disabled feature skips the check, `True` or an enabled feature turns a
+/// failing check into an error, `False` or an auto feature reports the result.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Required(FeatureState);
+
+impl Default for Required {
+    fn default() -> Self {
+        Self(FeatureState::Auto)
+    }
+}
+
+impl Required {
+    /// Whether the check should not run at all, i.e. a disabled feature.
+    pub(crate) fn skipped(&self) -> bool {
+        self.0 == FeatureState::Disabled
+    }
+
+    /// Turns the result of a check into an error if it was required.
+    pub(crate) fn verify(&self, what: impl Display, found: bool) -> anyhow::Result<bool> {
+        if !found && self.0 == FeatureState::Enabled {
+            anyhow::bail!("{} is required but was not found", what)
+        }
+        Ok(found)
+    }
+}
+
+impl StarlarkTypeRepr for Required {
+    type Canonical = <Either<bool, Feature> as StarlarkTypeRepr>::Canonical;
+
+    fn starlark_type_repr() -> Ty {
+        Either::<bool, Feature>::starlark_type_repr()
+    }
+}
+
+impl<'v> UnpackValue<'v> for Required {
+    type Error = Infallible;
+
+    fn unpack_value_impl(value: Value<'v>) -> Result<Option<Self>, Self::Error> {
+        if let Some(b) = value.unpack_bool() {
+            return Ok(Some(Required(if b {
+                FeatureState::Enabled
+            } else {
+                FeatureState::Auto
+            })));
+        }
+        Ok(value.downcast_ref::<Feature>().map(|f| Required(f.state)))
+    }
+}
@@ -3,21 +3,27 @@ use starlark::{
     values::starlark_value_as_type::StarlarkValueAsType,
 };
 
+use anyhow::Context;
+
+use crate::values::store::NamedPath;
 use crate::values::store::Store;
 
 mod compiler;
 mod configuration_data;
-mod configure_file;
+pub(crate) mod configure_file;
+mod dependency;
+mod feature;
 mod host_machine;
-// mod option;
-// mod dependency;
-// mod project;
-// mod version;
+mod option;
+pub(crate) mod project;
+mod provenance;
 pub(crate) mod store;
+mod version;
 
 #[starlark_module]
 pub fn type_toplevels(_: &mut GlobalsBuilder) {
     const compiler: StarlarkValueAsType<compiler::Compiler> = StarlarkValueAsType::new();
+    const feature: StarlarkValueAsType<feature::Feature> = StarlarkValueAsType::new();
 }
 
 #[starlark_module]
@@ -34,27 +40,50 @@ pub fn register_autoconf_toplevels(_: &mut GlobalsBuilder) {
 
 #[starlark_module]
 pub fn register_fn_toplevels(_: &mut GlobalsBuilder) {
-    fn config_in<'v>(eval: &mut Evaluator<'v, '_, '_>) -> starlark::Result<String> {
-        let store = Store::from_eval(eval)?;
-        Ok(store.config_in)
+    /// Returns the template passed as `--input [NAME=]PATH`, `name` defaults to the
+    /// basename without `.in` and can be omitted when there is a single template.
+    fn config_in<'v>(
+        #[starlark(require = pos)] name: Option<&str>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<String> {
+        let store = Store::get(eval)?;
+        let path = NamedPath::find(&store.inputs, name, true).context(
+            "failed to find the template, pass --input or use configure_file(input=None)",
+        )?;
+        Ok(path.to_string())
     }
-    fn config_out<'v>(eval: &mut Evaluator<'v, '_, '_>) -> starlark::Result<String> {
-        let store = Store::from_eval(eval)?;
-        Ok(store.config_out)
+    /// Returns the output passed as `--output [NAME=]PATH`, `name` defaults to the
+    /// basename and can be omitted when there is a single output.
+    fn config_out<'v>(
+        #[starlark(require = pos)] name: Option<&str>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<String> {
+        let store = Store::get(eval)?;
+        if store.coverage.is_some() && store.outputs.is_empty() {
+            // outputs are not written when reporting coverage
+            return Ok(name.unwrap_or_default().to_string());
+        }
+        let path = NamedPath::find(&store.outputs, name, false)
+            .context("failed to find the output, pass --output")?;
+        Ok(path.to_string())
     }
 }
 
 pub fn register_toplevels(builder: &mut GlobalsBuilder) {
-    // project::register_toplevels(builder);
-    // option::register_toplevels(builder);
-    // project::register_autoconconfig_toplevels(builder);
-    // dependency::register_toplevels(builder);
+    project::register_toplevels(builder);
+    project::register_project_info(builder);
+    option::register_toplevels(builder);
+    dependency::register_toplevels(builder);
+    version::register_toplevels(builder);
     configuration_data::register_toplevels(builder);
     host_machine::register_toplevels(builder);
     configure_file::register_toplevels(builder);
     builder.namespace("autoconf", |builder| {
         register_autoconf_toplevels(builder);
     });
+    builder.namespace("meson", |builder| {
+        project::register_project_info(builder);
+    });
     register_fn_toplevels(builder);
     type_toplevels(builder);
 }
@@ -1,17 +1,283 @@
-use starlark::{environment::GlobalsBuilder, starlark_module};
+use std::fmt;
+use std::fmt::Display;
+
+use anyhow::anyhow;
+use starlark::environment::GlobalsBuilder;
+use starlark::eval::Evaluator;
+use starlark::starlark_module;
+use starlark::values::list::UnpackList;
+use starlark::values::none::NoneType;
+use starlark::values::UnpackValue;
+use starlark::values::Value;
+
+use crate::values::feature::Feature;
+use crate::values::feature::FeatureState;
+use crate::values::store::Store;
+
+/// Build option types, see https://mesonbuild.com/Build-options.html#build-option-types
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum OptionType {
+    String,
+    Boolean,
+    Combo,
+    Integer,
+    Array,
+    Feature,
+}
+
+impl OptionType {
+    fn parse(ty: &str) -> anyhow::Result<Self> {
+        match ty {
+            "string" => Ok(Self::String),
+            "boolean" => Ok(Self::Boolean),
+            "combo" => Ok(Self::Combo),
+            "integer" => Ok(Self::Integer),
+            "array" => Ok(Self::Array),
+            "feature" => Ok(Self::Feature),
+            ty => Err(anyhow!(
+                "unknown option type `{}`, expected one of string, boolean, combo, integer, array, feature",
+                ty
+            )),
+        }
+    }
+}
+
+impl Display for OptionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String => write!(f, "string"),
+            Self::Boolean => write!(f, "boolean"),
+            Self::Combo => write!(f, "combo"),
+            Self::Integer => write!(f, "integer"),
+            Self::Array => write!(f, "array"),
+            Self::Feature => write!(f, "feature"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum OptionValue {
+    String(String),
+    Boolean(bool),
+    Integer(i64),
+    Array(Vec<String>),
+    Feature(FeatureState),
+}
+
+/// An option declared with `option()`.
+#[derive(Debug, Clone)]
+pub(crate) struct DeclaredOption {
+    pub value: OptionValue,
+    // only read when reporting unknown `--option`s, which docgen doesn't build
+    #[allow(dead_code)]
+    pub description: Option<String>,
+}
+
+struct Constraints<'a> {
+    name: &'a str,
+    ty: OptionType,
+    choices: &'a [String],
+    min: Option<i64>,
+    max: Option<i64>,
+}
+
+impl Constraints<'_> {
+    fn check_choice(&self, value: &str) -> anyhow::Result<()> {
+        if !self.choices.is_empty() && !self.choices.iter().any(|c| c == value) {
+            anyhow::bail!(
+                "value `{}` of option `{}` is not one of the choices {:?}",
+                value,
+                self.name,
+                self.choices
+            )
+        }
+        Ok(())
+    }
+
+    fn check_range(&self, value: i64) -> anyhow::Result<()> {
+        if self.min.is_some_and(|min| value < min) || self.max.is_some_and(|max| value > max) {
+            anyhow::bail!(
+                "value {} of option `{}` is out of range [{}, {}]",
+                value,
+                self.name,
+                self.min.map_or("-inf".to_string(), |v| v.to_string()),
+                self.max.map_or("inf".to_string(), |v| v.to_string()),
+            )
+        }
+        Ok(())
+    }
+
+    /// Parses a value given on the command line, e.g. `--option xattr=disabled`.
+    fn parse(&self, raw: &str) -> anyhow::Result<OptionValue> {
+        let value = match self.ty {
+            OptionType::String => OptionValue::String(raw.to_string()),
+            OptionType::Boolean => match raw {
+                "true" => OptionValue::Boolean(true),
+                "false" => OptionValue::Boolean(false),
+                _ => anyhow::bail!(
+                    "value `{}` of boolean option `{}` must be `true` or `false`",
+                    raw,
+                    self.name
+                ),
+            },
+            OptionType::Combo | OptionType::Feature => OptionValue::String(raw.to_string()),
+            OptionType::Integer => {
+                OptionValue::Integer(raw.trim().parse::<i64>().map_err(|_| {
+                    anyhow!(
+                        "value `{}` of integer option `{}` is not an integer",
+                        raw,
+                        self.name
+                    )
+                })?)
+            }
+            OptionType::Array => OptionValue::Array(if raw.is_empty() {
+                vec![]
+            } else {
+                raw.split(',').map(|s| s.trim().to_string()).collect()
+            }),
+        };
+        self.validate(value)
+    }
+
+    /// Converts a starlark value, e.g. the `default` of `option()`.
+    fn unpack(&self, value: Value) -> anyhow::Result<OptionValue> {
+        let mismatch = || {
+            anyhow!(
+                "default value {} of option `{}` does not match its type `{}`",
+                value.to_repr(),
+                self.name,
+                self.ty
+            )
+        };
+        let value = match self.ty {
+            OptionType::String | OptionType::Combo | OptionType::Feature => {
+                OptionValue::String(value.unpack_str().ok_or_else(mismatch)?.to_string())
+            }
+            OptionType::Boolean => OptionValue::Boolean(value.unpack_bool().ok_or_else(mismatch)?),
+            OptionType::Integer => {
+                OptionValue::Integer(value.unpack_i32().ok_or_else(mismatch)?.into())
+            }
+            OptionType::Array => OptionValue::Array(
+                UnpackList::<String>::unpack_value_opt(value)
+                    .ok_or_else(mismatch)?
+                    .items,
+            ),
+        };
+        self.validate(value)
+    }
+
+    /// Value used when neither a default nor a command line value is given.
+    fn implicit_default(&self) -> anyhow::Result<OptionValue> {
+        match self.ty {
+            OptionType::String => Ok(OptionValue::String(String::new())),
+            OptionType::Boolean => Ok(OptionValue::Boolean(true)),
+            OptionType::Combo => self
+                .choices
+                .first()
+                .map(|c| OptionValue::String(c.clone()))
+                .ok_or_else(|| anyhow!("combo option `{}` requires choices", self.name)),
+            OptionType::Integer => Ok(OptionValue::Integer(self.min.unwrap_or(0))),
+            OptionType::Array => Ok(OptionValue::Array(self.choices.to_vec())),
+            OptionType::Feature => Ok(OptionValue::String("auto".to_string())),
+        }
+    }
+
+    fn validate(&self, value: OptionValue) -> anyhow::Result<OptionValue> {
+        match (&value, self.ty) {
+            (OptionValue::String(s), OptionType::Feature) => {
+                let state = s.parse::<FeatureState>().map_err(|_| {
+                    anyhow!(
+                        "value `{}` of feature option `{}` must be one of enabled, disabled, auto",
+                        s,
+                        self.name
+                    )
+                })?;
+                Ok(OptionValue::Feature(state))
+            }
+            (OptionValue::String(s), OptionType::Combo) => {
+                self.check_choice(s)?;
+                Ok(value)
+            }
+            (OptionValue::Integer(i), _) => {
+                self.check_range(*i)?;
+                Ok(value)
+            }
+            (OptionValue::Array(items), _) => {
+                for item in items {
+                    self.check_choice(item)?;
+                }
+                Ok(value)
+            }
+            _ => Ok(value),
+        }
+    }
+}
 
 #[starlark_module]
-pub(crate) fn register_toplevels(globals: &mut GlobalsBuilder) {
-    fn get_option<'v>(
-        #[starlark(require = pos)] name: starlark::values::StringValue<'v>,
-    ) -> starlark::Result<String> {
-        Ok(String::new())
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn register_toplevels(_: &mut GlobalsBuilder) {
+    /// Declares a build option, see https://mesonbuild.com/Build-options.html
+    ///
+    /// Values are provided with `--option name=value` on the command line and
+    /// take precedence over the `default`.
+    fn option<'v>(
+        #[starlark(require = pos)] name: String,
+        #[starlark(require = named)] r#type: String,
+        #[starlark(require = named)] default: Option<Value<'v>>,
+        #[starlark(require = named)] choices: Option<UnpackList<String>>,
+        #[starlark(require = named)] min: Option<i32>,
+        #[starlark(require = named)] max: Option<i32>,
+        #[starlark(require = named)] description: Option<String>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<NoneType> {
+        let store = Store::get(eval)?;
+        let ty = OptionType::parse(&r#type)?;
+        let choices = choices.map(|c| c.items).unwrap_or_default();
+        if ty == OptionType::Combo && choices.is_empty() {
+            anyhow::bail!("combo option `{}` requires choices", name)
+        }
+        let constraints = Constraints {
+            name: &name,
+            ty,
+            choices: &choices,
+            min: min.map(i64::from),
+            max: max.map(i64::from),
+        };
+
+        let value = match store.options.iter().rev().find(|o| o.name == name) {
+            Some(o) => constraints.parse(&o.value)?,
+            None => match default {
+                Some(default) => constraints.unpack(default)?,
+                None => constraints.implicit_default()?,
+            },
+        };
+
+        let mut declared = store.declared_options.borrow_mut();
+        if declared.contains_key(&name) {
+            anyhow::bail!("option `{}` is declared more than once", name)
+        }
+        declared.insert(name, DeclaredOption { value, description });
+        Ok(NoneType)
     }
 
+    /// Returns the value of a build option declared with `option()`.
     fn get_option<'v>(
-        #[starlark(require = pos)] name: starlark::values::StringValue<'v>,
-        #[starlark(require = named)] ty: starlark::values::StringValue<'v>,
-    ) -> starlark::Result<String> {
-        Ok(String::new())
+        #[starlark(require = pos)] name: String,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<Value<'v>> {
+        let store = Store::get(eval)?;
+        let declared = store.declared_options.borrow();
+        let value = &declared
+            .get(&name)
+            .ok_or_else(|| anyhow!("unknown option `{}`, did you call option() first", name))?
+            .value;
+        let heap = eval.heap();
+        Ok(match value {
+            OptionValue::String(s) => heap.alloc(s.as_str()),
+            OptionValue::Feature(state) => heap.alloc_simple(Feature { state: *state }),
+            OptionValue::Boolean(b) => Value::new_bool(*b),
+            OptionValue::Integer(i) => heap.alloc(*i),
+            OptionValue::Array(items) => heap.alloc(items.clone()),
+        })
     }
 }
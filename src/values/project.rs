@@ -1,115 +1,160 @@
-use std::fmt;
-use std::fmt::Display;
-use std::fs::OpenOptions;
-use std::io::Write;
-
-use allocative::Allocative;
 use anyhow::Context;
-use starlark;
-use starlark::any::ProvidesStaticType;
+use either::Either;
 use starlark::environment::GlobalsBuilder;
 use starlark::eval::Evaluator;
+use starlark::starlark_module;
 use starlark::values::list::ListRef;
+use starlark::values::list::UnpackList;
 use starlark::values::none::NoneType;
 use starlark::values::tuple::UnpackTuple;
-use starlark::values::Coerce;
-use starlark::values::FreezeResult;
-use starlark::values::FrozenValue;
-use starlark::values::StarlarkValue;
-use starlark::values::StringValue;
 use starlark::values::Value;
-use starlark::values::ValueLike;
-use starlark::{starlark_complex_value, starlark_module};
-use starlark_derive::starlark_value;
-use starlark_derive::Freeze;
-use starlark_derive::NoSerialize;
-use starlark_derive::Trace;
 
-#[derive(Clone, Default, Debug, Trace, Freeze, ProvidesStaticType, Allocative, NoSerialize)]
-#[repr(C)]
-pub struct ProjectGen<'v, V: ValueLike<'v>> {
-    pub(crate) name: V::String,
-    pub(crate) version: V::String,
+use crate::values::store::Store;
+
+/// Metadata declared with `project()`.
+#[derive(Debug, Clone)]
+pub(crate) struct ProjectInfo {
+    pub name: String,
+    pub version: String,
 }
 
-impl<'v, V: ValueLike<'v>> Display for ProjectGen<'v, V> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "<project>")
+impl ProjectInfo {
+    fn from_eval<'v>(eval: &Evaluator<'v, '_, '_>) -> anyhow::Result<Self> {
+        Store::get(eval)?
+            .project
+            .borrow()
+            .clone()
+            .context("project is not declared, did you call project() first")
+    }
+
+    /// Encodes `major.minor.patch` as `major * 1000000 + minor * 1000 + patch`,
+    /// the scheme used by `ARCHIVE_VERSION_NUMBER` and alike.
+    fn version_number(&self) -> anyhow::Result<i32> {
+        let mut number = 0;
+        let mut components = self.version.split('.');
+        for _ in 0..3 {
+            let component = components.next().unwrap_or("0");
+            let digits: String = component
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            let component: i32 = digits
+                .parse()
+                .with_context(|| format!("version `{}` is not numeric", self.version))?;
+            if component >= 1000 {
+                anyhow::bail!(
+                    "version `{}` does not fit into a version number",
+                    self.version
+                )
+            }
+            number = number * 1000 + component;
+        }
+        Ok(number)
     }
 }
 
-unsafe impl<'v> Coerce<ProjectGen<'v, Value<'v>>> for ProjectGen<'static, FrozenValue> {}
+/// Flags added by one `add_project_arguments` or `add_project_link_arguments` call.
+// only read when writing `--copts-out`, which docgen doesn't build
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub(crate) struct Arguments {
+    pub languages: Vec<String>,
+    pub flags: Vec<String>,
+}
 
-#[starlark_value(type = "Project")]
-impl<'v, V: ValueLike<'v>> StarlarkValue<'v> for ProjectGen<'v, V> where Self: ProvidesStaticType<'v>
-{}
+/// Flags added with `add_project_arguments` and `add_project_link_arguments`, in
+/// call order.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ProjectArguments {
+    pub compile: Vec<Arguments>,
+    pub link: Vec<Arguments>,
+}
 
-starlark_complex_value!(pub(crate) Project<'v>);
+fn add_arguments<'v>(
+    arguments: &mut Vec<Arguments>,
+    args: UnpackTuple<Value<'v>>,
+    language: Either<String, UnpackList<String>>,
+) -> anyhow::Result<()> {
+    let mut flags = vec![];
+    for arg in args.into_iter() {
+        if let Some(arg) = arg.unpack_str() {
+            flags.push(arg.to_string());
+        } else if let Some(list) = ListRef::from_value(arg) {
+            for arg in list.iter() {
+                flags.push(
+                    arg.unpack_str()
+                        .context("arguments must be strings or lists of strings")?
+                        .to_string(),
+                );
+            }
+        } else {
+            anyhow::bail!("arguments must be strings or lists of strings")
+        }
+    }
 
-const HEAP_HIDDEN_PROJECT: &str = "#hidden_project#";
+    let languages = language.either(|l| vec![l], |l| l.items);
+    arguments.push(Arguments { languages, flags });
+    Ok(())
+}
 
+/// `project_name()` and `project_version()`, registered both at the top level and
+/// in the `meson` namespace.
 #[starlark_module]
-pub fn register_autoconconfig_toplevels(_: &mut GlobalsBuilder) {
-    fn project_version<'v>(eval: &mut Evaluator<'v, '_, '_>) -> starlark::Result<Value<'v>> {
-        let project = eval
-            .module()
-            .get(HEAP_HIDDEN_PROJECT)
-            .ok_or("did you call project() first");
-        // thats a lot of unwrap, find a way to turn Result into starlark::Result.
-        Ok(project
-            .unwrap()
-            .downcast_ref::<Project>()
-            .unwrap()
-            .version
-            .to_value())
+pub(crate) fn register_project_info(_: &mut GlobalsBuilder) {
+    /// Returns the name passed to `project()`.
+    fn project_name<'v>(eval: &mut Evaluator<'v, '_, '_>) -> anyhow::Result<String> {
+        Ok(ProjectInfo::from_eval(eval)?.name)
+    }
+
+    /// Returns the version passed to `project()`.
+    fn project_version<'v>(eval: &mut Evaluator<'v, '_, '_>) -> anyhow::Result<String> {
+        Ok(ProjectInfo::from_eval(eval)?.version)
     }
 }
 
 #[starlark_module]
 pub(crate) fn register_toplevels(globals: &mut GlobalsBuilder) {
+    /// Declares the project, see https://mesonbuild.com/Reference-manual_functions.html#project
     fn project<'v>(
-        #[starlark(require = pos)] name: starlark::values::StringValue<'v>,
-        #[starlark(require = named)] version: starlark::values::StringValue<'v>,
+        #[starlark(require = pos)] name: String,
+        #[starlark(require = named, default = "undefined".to_owned())] version: String,
         eval: &mut Evaluator<'v, '_, '_>,
-    ) -> starlark::Result<NoneType> {
-        eval.module().set(
-            HEAP_HIDDEN_PROJECT,
-            eval.heap().alloc_complex(Project {
-                name: name,
-                version: version,
-            }),
-        );
+    ) -> anyhow::Result<NoneType> {
+        let mut project = Store::get(eval)?.project.borrow_mut();
+        if let Some(project) = project.as_ref() {
+            anyhow::bail!("project() was already called for `{}`", project.name)
+        }
+        *project = Some(ProjectInfo { name, version });
         Ok(NoneType)
     }
 
+    /// Returns the project version as `major * 1000000 + minor * 1000 + patch`,
+    /// e.g. `3007009` for `3.7.9`.
+    fn project_version_number<'v>(eval: &mut Evaluator<'v, '_, '_>) -> anyhow::Result<i32> {
+        ProjectInfo::from_eval(eval)?.version_number()
+    }
+
+    /// Adds compiler flags for the given languages, they are written to the file
+    /// passed as `--copts-out`.
     fn add_project_arguments<'v>(
-        #[starlark(args)] args: UnpackTuple<Value>,
-        #[starlark(require = named)] language: Option<StringValue<'v>>,
+        #[starlark(args)] args: UnpackTuple<Value<'v>>,
+        #[starlark(require = named)] language: Either<String, UnpackList<String>>,
+        eval: &mut Evaluator<'v, '_, '_>,
     ) -> anyhow::Result<NoneType> {
-        let mut file = OpenOptions::new()
-            .append(true)
-            .create(true)
-            .write(true)
-            .open("arguments.txt")?;
-
-        for arg in args.into_iter() {
-            match arg.get_type() {
-                "list" => {
-                    for arg in ListRef::from_value(arg)
-                        .context("failed to cast to list")?
-                        .iter()
-                    {
-                        assert!(arg.get_type() == "string");
-                        file.write((arg.to_str().to_string() + " ").as_bytes())?;
-                    }
-                }
-                "string" => {
-                    file.write((arg.to_str().to_string() + " ").as_bytes())?;
-                }
-                _ => anyhow::bail!("add_project_arguments supports list of strings, or string"),
-            }
-        }
+        let mut arguments = Store::get(eval)?.project_arguments.borrow_mut();
+        add_arguments(&mut arguments.compile, args, language)?;
+        Ok(NoneType)
+    }
 
+    /// Adds linker flags for the given languages, they are written to the file
+    /// passed as `--linkopts-out`.
+    fn add_project_link_arguments<'v>(
+        #[starlark(args)] args: UnpackTuple<Value<'v>>,
+        #[starlark(require = named)] language: Either<String, UnpackList<String>>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<NoneType> {
+        let mut arguments = Store::get(eval)?.project_arguments.borrow_mut();
+        add_arguments(&mut arguments.link, args, language)?;
         Ok(NoneType)
     }
 }
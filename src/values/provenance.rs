@@ -0,0 +1,34 @@
+//! Tracks which check produced a configuration value, so generated headers can be
+//! annotated with e.g. `cc.has_header("sys/acl.h") at config.star:162`.
+
+use starlark::eval::Evaluator;
+
+use crate::values::store::Store;
+
+/// The last check that ran, until a value is set.
+#[derive(Debug, Clone)]
+pub(crate) struct Check {
+    description: String,
+}
+
+/// Records a check such as `cc.has_header("sys/acl.h")`.
+pub(crate) fn record_check(eval: &Evaluator, description: String) -> anyhow::Result<()> {
+    *Store::get(eval)?.last_check.borrow_mut() = Some(Check { description });
+    Ok(())
+}
+
+/// Describes where a value set at the current location comes from, naming the most
+/// recent check not credited to a value yet. It is either called within the same
+/// expression, e.g. `cdata.set("X", cc.has_header("x.h"))`, or guards the set, e.g.
+/// `if cc.has_header("x.h"): cdata.set("X", 1)`.
+pub(crate) fn provenance(eval: &Evaluator) -> anyhow::Result<Option<String>> {
+    let Some(location) = eval.call_stack_top_location() else {
+        return Ok(None);
+    };
+    let line = location.resolve().begin_file_line();
+    let check = Store::get(eval)?.last_check.borrow_mut().take();
+    Ok(Some(match check {
+        Some(check) => format!("{} at {}", check.description, line),
+        None => format!("set at {}", line),
+    }))
+}
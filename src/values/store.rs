@@ -1,15 +1,29 @@
 use anyhow::{anyhow, Result};
-use starlark::{any::ProvidesStaticType, eval::Evaluator};
+use starlark::{any::ProvidesStaticType, collections::SmallMap, eval::Evaluator};
 use std::cell::RefCell;
 
+use crate::values::option::DeclaredOption;
+use crate::values::project::{ProjectArguments, ProjectInfo};
+use crate::values::provenance::Check;
+use crate::values::version::Version;
+
 #[derive(Debug, ProvidesStaticType, Default)]
 pub struct Store {
-    pub config_in: String,
-    pub config_out: String,
+    pub inputs: Vec<NamedPath>,
+    pub outputs: Vec<NamedPath>,
     pub cc_compiler: String,
     pub cc_executable: String,
     pub cc_args: Vec<String>,
     pub dependencies: RefCell<Vec<DDependency>>,
+    pub options: Vec<DOption>,
+    pub declared_options: RefCell<SmallMap<String, DeclaredOption>>,
+    pub project: RefCell<Option<ProjectInfo>>,
+    pub project_arguments: RefCell<ProjectArguments>,
+    pub strict: bool,
+    /// Set by `starconf coverage`, `configure_file` reports how templates are covered
+    /// instead of writing them.
+    pub coverage: Option<RefCell<Vec<serde_json::Value>>>,
+    pub last_check: RefCell<Option<Check>>,
 }
 
 impl Store {
@@ -21,49 +35,154 @@ impl Store {
             .ok_or(anyhow!("invalid value type"))?;
 
         Ok(Self {
-            config_in: store.config_in.clone(),
-            config_out: store.config_out.clone(),
+            inputs: store.inputs.clone(),
+            outputs: store.outputs.clone(),
             cc_compiler: store.cc_compiler.clone(),
             cc_executable: store.cc_executable.clone(),
             cc_args: store.cc_args.clone(),
             dependencies: store.dependencies.clone(),
+            options: store.options.clone(),
+            declared_options: store.declared_options.clone(),
+            project: store.project.clone(),
+            project_arguments: store.project_arguments.clone(),
+            strict: store.strict,
+            coverage: store.coverage.clone(),
+            last_check: store.last_check.clone(),
         })
     }
+
+    /// Borrows the store of the evaluator, unlike `from_eval` changes made through
+    /// its `RefCell`s are visible to subsequent calls.
+    pub fn get<'a>(eval: &Evaluator<'_, 'a, '_>) -> Result<&'a Self> {
+        eval.extra
+            .ok_or(anyhow!("extra value is not set"))?
+            .downcast_ref::<Store>()
+            .ok_or(anyhow!("invalid value type"))
+    }
 }
 
-// Define the Dependency struct
+// Dependency provided on the command line, e.g. `--dependency zlib=1.3`
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct DDependency {
     pub name: String,
-    pub semver: semver::Version,
+    pub version: Version,
 }
 
-// Implement custom parsing for Dependency
 impl std::str::FromStr for DDependency {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let parts: Vec<&str> = s.split('=').collect();
         if parts.len() != 2 {
-            return Err(format!("Invalid format: '{}'. Expected 'name=semver'", s));
+            return Err(format!("Invalid format: '{}'. Expected 'name=version'", s));
         }
 
         let name = parts[0].trim();
-        let semver = parts[1].trim();
+        let version = parts[1].trim();
 
-        if name.is_empty() || semver.is_empty() {
-            return Err(format!("Name or semver cannot be empty in '{}'", s));
+        if name.is_empty() || version.is_empty() {
+            return Err(format!("Name or version cannot be empty in '{}'", s));
         }
 
-        let ver = semver::Version::parse(semver);
-        if ver.is_err() {
-            return Err(format!("semver is corrupted '{}'", semver));
+        Ok(Self {
+            name: name.to_string(),
+            version: Version::new(version),
+        })
+    }
+}
+
+// Build option given on the command line
+#[derive(Debug, Clone)]
+pub struct DOption {
+    pub name: String,
+    pub value: String,
+}
+
+impl std::str::FromStr for DOption {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once('=')
+            .ok_or(format!("Invalid format: '{}'. Expected 'name=value'", s))?;
+
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(format!("Name cannot be empty in '{}'", s));
         }
 
         Ok(Self {
             name: name.to_string(),
-            semver: ver.unwrap(),
+            value: value.to_string(),
         })
     }
 }
+
+// Template or output given on the command line as `[NAME=]PATH`, e.g. `--output version.h=out/version.h`
+#[derive(Debug, Clone)]
+pub struct NamedPath {
+    pub name: Option<String>,
+    pub path: String,
+}
+
+impl std::str::FromStr for NamedPath {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, path) = match s.split_once('=') {
+            Some((name, path)) => (Some(name.trim().to_string()), path),
+            None => (None, s),
+        };
+        if name.as_ref().is_some_and(|name| name.is_empty()) || path.is_empty() {
+            return Err(format!("Name or path cannot be empty in '{}'", s));
+        }
+        Ok(Self {
+            name,
+            path: path.to_string(),
+        })
+    }
+}
+
+impl NamedPath {
+    /// The explicit name or the basename of the path, without `.in` for templates so
+    /// `config.h.in` and `config.h` share a name.
+    pub fn name(&self, template: bool) -> String {
+        if let Some(name) = &self.name {
+            return name.clone();
+        }
+        let base = std::path::Path::new(&self.path)
+            .file_name()
+            .map(|base| base.to_string_lossy().to_string())
+            .unwrap_or_default();
+        match base.strip_suffix(".in") {
+            Some(stripped) if template => stripped.to_string(),
+            _ => base,
+        }
+    }
+
+    /// Finds the path called `name`, without a name there must be a single path.
+    pub fn find<'a>(paths: &'a [NamedPath], name: Option<&str>, template: bool) -> Result<&'a str> {
+        let names = || {
+            paths
+                .iter()
+                .map(|p| p.name(template))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match name {
+            Some(name) => paths
+                .iter()
+                .find(|p| p.name(template) == name)
+                .map(|p| p.path.as_str())
+                .ok_or_else(|| anyhow!("no path named `{}`, expected one of: {}", name, names())),
+            None => match paths {
+                [path] => Ok(&path.path),
+                [] => Err(anyhow!("no path was given")),
+                _ => Err(anyhow!(
+                    "several paths were given, pass one of: {}",
+                    names()
+                )),
+            },
+        }
+    }
+}
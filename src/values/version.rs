@@ -1,6 +1,9 @@
+use std::cmp::Ordering;
+use std::hash::Hash;
+
 use allocative::Allocative;
-use anyhow::Context;
-use semver::{Version as SemVer, VersionReq};
+use starlark::collections::StarlarkHasher;
+use starlark::environment::GlobalsBuilder;
 use starlark::environment::Methods;
 use starlark::environment::MethodsBuilder;
 use starlark::environment::MethodsStatic;
@@ -10,41 +13,137 @@ use starlark::values::starlark_value;
 use starlark::values::NoSerialize;
 use starlark::values::ProvidesStaticType;
 use starlark::values::StarlarkValue;
-use starlark::values::StringValue;
 use starlark::values::Value;
+use starlark::values::ValueError;
 use starlark::values::ValueLike;
 
+/// Part of a version, runs of digits and runs of letters are compared separately.
+#[derive(Clone, Debug, PartialEq, Eq, Allocative)]
+enum Component {
+    Alpha(String),
+    // digits without leading zeros, compared by length first so any length works
+    Numeric(String),
+}
+
+impl Ord for Component {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            // a non-digit sequence sorts before a digit sequence
+            (Component::Alpha(_), Component::Numeric(_)) => Ordering::Less,
+            (Component::Numeric(_), Component::Alpha(_)) => Ordering::Greater,
+            (Component::Alpha(a), Component::Alpha(b)) => a.cmp(b),
+            (Component::Numeric(a), Component::Numeric(b)) => {
+                a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+            }
+        }
+    }
+}
+
+impl PartialOrd for Component {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A version compared the way meson's `version_compare` does, so `1.2`,
+/// `2.4.0rc1`, `1.0.2k` or `3.7.9.1` are all valid.
 #[derive(Clone, Debug, derive_more::Display, ProvidesStaticType, NoSerialize, Allocative)]
-#[display("<version>")]
+#[display("{}", raw)]
 pub(crate) struct Version {
-    #[allocative(skip)]
-    version: SemVer,
+    raw: String,
+    components: Vec<Component>,
 }
 
 impl Version {
-    pub fn new(ver: &str) -> anyhow::Result<Self> {
-        let version = SemVer::parse(ver)?;
-        Ok(Self { version })
+    pub fn new(ver: &str) -> Self {
+        let mut components = vec![];
+        let mut chars = ver.chars().peekable();
+        while let Some(&c) = chars.peek() {
+            let mut run = String::new();
+            if c.is_ascii_digit() {
+                while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+                    run.push(c);
+                }
+                let digits = run.trim_start_matches('0');
+                components.push(Component::Numeric(digits.to_string()));
+            } else if c.is_ascii_alphabetic() {
+                while let Some(c) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+                    run.push(c);
+                }
+                components.push(Component::Alpha(run));
+            } else {
+                // separators are discarded
+                chars.next();
+            }
+        }
+        Self {
+            raw: ver.to_string(),
+            components,
+        }
+    }
+
+    /// Evaluates a constraint such as `>=1.2`, a missing operator means `==`.
+    pub fn matches(&self, constraint: &str) -> bool {
+        let constraint = constraint.trim_start();
+        let (op, rest) = [">=", "<=", "!=", "==", "=", "<", ">"]
+            .iter()
+            .find_map(|op| constraint.strip_prefix(op).map(|rest| (*op, rest)))
+            .unwrap_or(("==", constraint));
+        let ordering = self.cmp(&Version::new(rest));
+        match op {
+            ">=" => ordering.is_ge(),
+            "<=" => ordering.is_le(),
+            "!=" => ordering.is_ne(),
+            "<" => ordering.is_lt(),
+            ">" => ordering.is_gt(),
+            _ => ordering.is_eq(),
+        }
     }
 }
 
-impl From<SemVer> for Version {
-    fn from(version: SemVer) -> Self {
-        Self { version }
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.components == other.components
+    }
+}
+
+impl Eq for Version {}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for (ours, theirs) in self.components.iter().zip(other.components.iter()) {
+            match ours.cmp(theirs) {
+                Ordering::Equal => continue,
+                ordering => return ordering,
+            }
+        }
+        // the version with a suffix remaining is greater
+        self.components.len().cmp(&other.components.len())
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn unpack_version(value: Value) -> Option<Version> {
+    match value.unpack_str() {
+        Some(s) => Some(Version::new(s)),
+        None => value.downcast_ref::<Version>().cloned(),
     }
 }
 
 #[starlark_module]
 fn version_methods(builder: &mut MethodsBuilder) {
-    fn version_compare<'v>(
-        this: Value<'v>,
-        #[starlark(require = pos)] compare: StringValue,
+    /// Compares against a constraint such as `>=1.2`, see
+    /// https://mesonbuild.com/Reference-manual_elementary_str.html#strversion_compare
+    fn version_compare(
+        this: &Version,
+        #[starlark(require = pos)] compare: &str,
     ) -> starlark::Result<bool> {
-        let version = this.downcast_ref::<Version>().unwrap();
-
-        let version_req =
-            VersionReq::parse(compare.as_str()).context("failed to parse semver range")?;
-        Ok(version_req.matches(&version.version))
+        Ok(this.matches(compare))
     }
 }
 
@@ -56,4 +155,116 @@ impl<'v> StarlarkValue<'v> for Version {
         static RES: MethodsStatic = MethodsStatic::new();
         RES.methods(version_methods)
     }
+
+    fn equals(&self, other: Value<'v>) -> starlark::Result<bool> {
+        // like `compare`, strings are parsed, so `version("1.0") == "1.0"`
+        Ok(unpack_version(other).is_some_and(|other| *self == other))
+    }
+
+    fn compare(&self, other: Value<'v>) -> starlark::Result<Ordering> {
+        match unpack_version(other) {
+            Some(other) => Ok(self.cmp(&other)),
+            None => ValueError::unsupported_with(self, "compare", other),
+        }
+    }
+
+    // only consistent between versions, a string equal to a version hashes as a
+    // string, so versions and strings should not be mixed as dict keys
+    fn write_hash(&self, hasher: &mut StarlarkHasher) -> starlark::Result<()> {
+        for component in &self.components {
+            match component {
+                Component::Alpha(s) | Component::Numeric(s) => s.hash(hasher),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[starlark_module]
+pub(crate) fn register_toplevels(_: &mut GlobalsBuilder) {
+    /// Parses a version, it can be compared with other versions and strings, e.g.
+    /// `version("1.10") > "1.9"`, use `version_compare` to match a constraint.
+    ///
+    /// Starlark lets a string literal decide `==` on its own, so `v == "1.0"` is
+    /// always false, compare with `version("1.0")` instead.
+    #[starlark(as_type = Version)]
+    fn version(#[starlark(require = pos)] ver: &str) -> starlark::Result<Version> {
+        Ok(Version::new(ver))
+    }
+
+    /// Compares two versions, e.g. `version_compare("2.4.0rc1", ">=2.4")`, see
+    /// https://mesonbuild.com/Reference-manual_elementary_str.html#strversion_compare
+    fn version_compare(
+        #[starlark(require = pos)] a: Value,
+        #[starlark(require = pos)] b: &str,
+    ) -> anyhow::Result<bool> {
+        let a = unpack_version(a)
+            .ok_or_else(|| anyhow::anyhow!("expected a version or string, got {}", a.get_type()))?;
+        Ok(a.matches(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use starlark::values::Heap;
+
+    use super::*;
+
+    fn cmp(a: &str, b: &str) -> Ordering {
+        Version::new(a).cmp(&Version::new(b))
+    }
+
+    #[test]
+    fn numeric_parts() {
+        assert_eq!(cmp("1.10", "1.9"), Ordering::Greater);
+        assert_eq!(cmp("1.2", "1.2.0"), Ordering::Less);
+        assert_eq!(cmp("3.7.9.1", "3.7.9"), Ordering::Greater);
+        assert_eq!(
+            cmp("100000000000000000000", "99999999999999999999"),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn leading_zeros() {
+        assert_eq!(cmp("1.02", "1.2"), Ordering::Equal);
+        assert_eq!(cmp("1.010", "1.9"), Ordering::Greater);
+        assert_eq!(cmp("1.0", "1.00"), Ordering::Equal);
+    }
+
+    #[test]
+    fn alpha_parts() {
+        // letters sort before digits
+        assert_eq!(cmp("2.4.0rc1", "2.4.0.1"), Ordering::Less);
+        assert_eq!(cmp("1.0.2k", "1.0.2j"), Ordering::Greater);
+        assert_eq!(cmp("1.0.2k", "1.0.2"), Ordering::Greater);
+        assert_eq!(cmp("1.0a", "1.0b"), Ordering::Less);
+    }
+
+    #[test]
+    fn operators() {
+        let v = Version::new("2.4.0rc1");
+        assert!(v.matches(">=2.4"));
+        assert!(v.matches(">2.4"));
+        assert!(v.matches("<2.4.0.1"));
+        assert!(v.matches("<=2.4.0rc1"));
+        assert!(v.matches("!=2.4.0"));
+        assert!(v.matches("==2.4.0rc1"));
+        assert!(v.matches("=2.4.0rc1"));
+        assert!(v.matches("2.4.0rc1"));
+        assert!(v.matches(" >= 2.4"));
+        assert!(!v.matches("<2.4"));
+        assert!(!v.matches("2.4.0"));
+    }
+
+    #[test]
+    fn equals_strings() {
+        let heap = Heap::new();
+        let v = Version::new("1.0");
+        assert!(v.equals(heap.alloc_str("1.0").to_value()).unwrap());
+        assert!(v.equals(heap.alloc_str("1.00").to_value()).unwrap());
+        assert!(!v.equals(heap.alloc_str("1.1").to_value()).unwrap());
+        assert!(v.equals(heap.alloc_simple(Version::new("1.0."))).unwrap());
+        assert!(!v.equals(heap.alloc(1)).unwrap());
+    }
 }
@@ -0,0 +1,121 @@
+//! Runs starconf on the configs of `tests/cli/*/` with the flags a test is about.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn case(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/cli")
+        .join(name)
+}
+
+fn run(name: &str, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_starconf"))
+        .current_dir(case(name))
+        .args(["--config", "config.star", "--compiler", "gcc", "--cc", "cc"])
+        .args(args)
+        .env("RUST_BACKTRACE", "0")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn strict_reports_missing_and_unused_keys() {
+    let out = tempfile::tempdir().unwrap();
+    let output = out.path().join("config.h");
+    let output = output.to_str().unwrap();
+    let result = run(
+        "strict",
+        &["--input", "config.h.in", "--output", output, "--strict"],
+    );
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(!result.status.success());
+    assert!(
+        stderr.contains("config.h.in:2: HAVE_BAR is not set"),
+        "{}",
+        stderr
+    );
+    assert!(
+        stderr.contains("HAVE_UNUSED is set but never used by config.h.in"),
+        "{}",
+        stderr
+    );
+    assert!(!Path::new(output).exists());
+}
+
+#[test]
+fn not_strict_writes_missing_keys_undefined() {
+    let out = tempfile::tempdir().unwrap();
+    let output = out.path().join("config.h");
+    let output = output.to_str().unwrap();
+    let result = run("strict", &["--input", "config.h.in", "--output", output]);
+    assert!(
+        result.status.success(),
+        "{}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    assert_eq!(
+        fs::read_to_string(output).unwrap(),
+        "#define HAVE_FOO\n/* #undef HAVE_BAR */\n"
+    );
+}
+
+#[test]
+fn named_inputs_and_outputs() {
+    let out = tempfile::tempdir().unwrap();
+    let path = |name: &str| out.path().join(name).to_str().unwrap().to_owned();
+    let (config, version, generated) = (path("config.h"), path("v.h"), path("generated.h"));
+    let result = run(
+        "named",
+        &[
+            "--input",
+            "config.h.in",
+            "--input",
+            "version=version.h.in",
+            "--output",
+            &config,
+            "--output",
+            &format!("version={}", version),
+            "--output",
+            &generated,
+        ],
+    );
+    assert!(
+        result.status.success(),
+        "{}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    assert_eq!(fs::read_to_string(config).unwrap(), "#define HAVE_FOO\n");
+    assert_eq!(
+        fs::read_to_string(version).unwrap(),
+        "#define VERSION \"1.2\"\n"
+    );
+    assert!(fs::read_to_string(generated)
+        .unwrap()
+        .contains("#define HAVE_FOO\n"));
+}
+
+#[test]
+fn unknown_input_name() {
+    let out = tempfile::tempdir().unwrap();
+    let output = out.path().join("config.h");
+    let result = run(
+        "named",
+        &[
+            "--input",
+            "config.h.in",
+            "--input",
+            "other=version.h.in",
+            "--output",
+            output.to_str().unwrap(),
+        ],
+    );
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(!result.status.success());
+    assert!(
+        stderr.contains("no path named `version`, expected one of: config.h, other"),
+        "{}",
+        stderr
+    );
+}
@@ -0,0 +1,39 @@
+//! Evaluates the configs of `tests/configuration_data/*/`, which `fail()` when a
+//! `configuration_data` method doesn't return what they expect.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run(case: &str) -> Output {
+    let out = tempfile::tempdir().unwrap();
+    Command::new(env!("CARGO_BIN_EXE_starconf"))
+        .current_dir(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/configuration_data")
+                .join(case),
+        )
+        .args(["--config", "config.star", "--compiler", "gcc", "--cc", "cc"])
+        .arg("--output")
+        .arg(out.path().join("config.h"))
+        .env("RUST_BACKTRACE", "0")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn methods() {
+    let result = run("methods");
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(result.status.success(), "{}", stderr);
+    assert!(stderr.lines().any(|l| l == "ok"), "{}", stderr);
+}
+
+#[test]
+fn frozen() {
+    let result = run("frozen");
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(!result.status.success());
+    assert!(stderr.lines().any(|l| l == "copied"), "{}", stderr);
+    assert!(stderr.contains("error: Immutable"), "{}", stderr);
+    assert!(stderr.contains("config.star:10"), "{}", stderr);
+}
@@ -0,0 +1,83 @@
+//! Renders the templates of `tests/configure_file/*/` and compares them with the
+//! `expected.*` file next to them.
+//!
+//! Every case has a `config.star` calling `configure_file` with `config_in()`, which is
+//! the case's `config.h.in` if it has one, and `config_out()`. Run with
+//! `STARCONF_BLESS=1` to rewrite the expected files after an intended change.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn render(case: &Path, output: &Path) -> String {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_starconf"));
+    command.current_dir(case).args(["--config", "config.star"]);
+    if case.join("config.h.in").exists() {
+        command.args(["--input", "config.h.in"]);
+    }
+    let result = command
+        .arg("--output")
+        .arg(output)
+        .args(["--compiler", "gcc", "--cc", "cc"])
+        .env("RUST_BACKTRACE", "0")
+        .output()
+        .unwrap();
+    assert!(
+        result.status.success(),
+        "{} failed:\n{}",
+        case.display(),
+        String::from_utf8_lossy(&result.stderr)
+    );
+    fs::read_to_string(output).unwrap()
+}
+
+#[test]
+fn golden() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/configure_file");
+    let out = tempfile::tempdir().unwrap();
+    let bless = std::env::var_os("STARCONF_BLESS").is_some();
+
+    let mut cases: Vec<_> = fs::read_dir(&root)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    cases.sort();
+    assert!(!cases.is_empty());
+
+    let mut failed = vec![];
+    for case in cases {
+        let name = case.file_name().unwrap().to_string_lossy().into_owned();
+        let rendered = render(&case, &out.path().join(&name));
+        // generated headers in other formats are compared with e.g. `expected.json`
+        let expected_path = fs::read_dir(&case)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .find(|path| path.file_stem().is_some_and(|stem| stem == "expected"))
+            .unwrap_or_else(|| case.join("expected.h"));
+        if bless {
+            fs::write(&expected_path, &rendered).unwrap();
+            continue;
+        }
+        let expected = fs::read_to_string(&expected_path).unwrap_or_default();
+        if rendered != expected {
+            let (line, (want, got)) = expected
+                .lines()
+                .chain(std::iter::repeat(""))
+                .zip(rendered.lines().chain(std::iter::repeat("")))
+                .take(expected.lines().count().max(rendered.lines().count()))
+                .enumerate()
+                .find(|(_, (want, got))| want != got)
+                // only the trailing newline differs
+                .unwrap_or((expected.lines().count(), ("", "")));
+            eprintln!(
+                "{}:{}:\n  expected: {}\n  rendered: {}",
+                expected_path.display(),
+                line + 1,
+                want,
+                got
+            );
+            failed.push(name);
+        }
+    }
+    assert!(failed.is_empty(), "rendered templates differ: {:?}", failed);
+}
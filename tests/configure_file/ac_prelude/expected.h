@@ -0,0 +1,11 @@
+#define HAVE_SYS_SOCKET_H 1
+/* #undef HAVE_NOT_A_HEADER_H */
+#define HAVE_STRLEN 1
+#define HAVE_MEMCPY 1
+/* #undef HAVE_NOT_A_FUNCTION */
+#define HAVE_SIZE_T 1
+/* #undef HAVE_STRUCT_NOT_A_TYPE */
+#define SIZEOF_CHAR_P 8
+#define SIZEOF_UNSIGNED_LONG_LONG 8
+#define HAVE_STRUCT_STAT_ST_MODE 1
+#define TR_CPP "CHAR_P SYS_TYPES_H"
@@ -0,0 +1,6 @@
+/* Define if * /usr/include/foo.h* / exists */
+/* set at config.star:3 */
+#define HAVE_FOO
+/* /* nested * / comment */
+/* set at config.star:4 */
+#define HAVE_BAR 0
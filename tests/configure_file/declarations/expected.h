@@ -0,0 +1,5 @@
+#define HAVE_DECL_GNU_ONLY 0
+#define HAVE_DECL_GNU_ONLY_CPP 1
+#define HAVE_DECL_GNU_ONLY_DEFINED 1
+#define HAVE_DECL_SIZE_MAX 1
+#define HAVE_DECL_NOT_DECLARED 0
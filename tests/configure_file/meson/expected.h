@@ -0,0 +1,42 @@
+/* config.h.meson, in the style of projects converting an autoheader template */
+#ifndef CONFIG_H
+#define CONFIG_H
+
+/* Name of package */
+#define PACKAGE "libfoo"
+
+/* Define to the version of this package. */
+#define PACKAGE_VERSION "1.4.0"
+
+/* Define to 1 if you have the <sys/random.h> header file. */
+#define HAVE_SYS_RANDOM_H 1
+
+/* Define to 1 if you have the `getrandom' function. */
+#define HAVE_GETRANDOM
+
+/* Define to 1 if you have the `memfd_create' function. */
+#undef HAVE_MEMFD_CREATE
+
+/* The size of `size_t', as computed by sizeof. */
+#define SIZEOF_SIZE_T 8
+
+/* Directives may be indented */
+#define ENABLE_NLS 1
+
+/* Define if the compiler supports __attribute__((visibility)). */
+/* #undef HAVE_VISIBILITY */
+
+#define GETTEXT_PACKAGE "libfoo"
+#define LOCALEDIR "/usr/local/share/locale"
+
+/* ${VAR} is not a reference in meson templates */
+#define SHELL_VAR "${HOME}"
+
+/* An escaped reference is kept */
+#define LITERAL "@PACKAGE@"
+
+/* A doubled at sign is written once */
+#define AT_SIGN "@"
+#define EMAIL "bugs@libfoo.org"
+
+#endif
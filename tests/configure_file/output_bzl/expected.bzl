@@ -0,0 +1,16 @@
+# Autogenerated by starconf.
+# Do not edit, your changes will be lost.
+
+# Define if bar() exists.
+HAVE_BAR = False
+
+# Define if foo.h exists.
+#
+# It is only checked on Unix.
+HAVE_FOO = True
+
+PACKAGE_NAME = "name with \"quotes\" and `ticks`"
+
+SIZEOF_LONG = 8
+
+VERSION_MACRO = "1.2"
@@ -0,0 +1,17 @@
+; Autogenerated by starconf.
+; Do not edit, your changes will be lost.
+
+; Define if bar() exists.
+%undef HAVE_BAR
+
+; Define if foo.h exists.
+;
+; It is only checked on Unix.
+%define HAVE_FOO
+
+%define PACKAGE_NAME `name with \"quotes\" and \`ticks\``
+
+%define SIZEOF_LONG 8
+
+%define VERSION_MACRO 1.2
+
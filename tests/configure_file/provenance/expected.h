@@ -0,0 +1,10 @@
+/* cc.has_header("stddef.h") at config.star:5 */
+#define HAVE_STDDEF_H
+/* cc.has_header("stdint.h") at config.star:9 */
+#define HAVE_STDINT_H
+/* cc.has_header("limits.h") at config.star:12 */
+#define HAVE_LIMITS_H 1
+/* cc.has_header("float.h") at config.star:15 */
+#define HAVE_FLOAT_H
+/* set at config.star:19 */
+#define VERSION "1.0"
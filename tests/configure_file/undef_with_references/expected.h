@@ -0,0 +1,7 @@
+/* Hand written template mixing references with a plain #undef, which is not an
+   autoheader template. */
+#define PACKAGE_VERSION "1.0"
+#define PACKAGE_PREFIX "/usr"
+
+/* Left as is since the template is not an autoheader one */
+#undef HAVE_FOO
@@ -0,0 +1,57 @@
+//! Runs `starconf coverage` on `tests/coverage/`, whose template has a key that is
+//! set, one that is not and whose configuration has a key the template doesn't use.
+
+use std::path::Path;
+use std::process::Command;
+
+fn coverage(format: &str, output: &Path) -> String {
+    let result = Command::new(env!("CARGO_BIN_EXE_starconf"))
+        .current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/coverage"))
+        .args([
+            "coverage",
+            "--config",
+            "config.star",
+            "--input",
+            "config.h.in",
+        ])
+        .arg("--output")
+        .arg(output)
+        .args(["--compiler", "gcc", "--cc", "cc", "--format", format])
+        .env("RUST_BACKTRACE", "0")
+        .output()
+        .unwrap();
+    assert!(
+        result.status.success(),
+        "{}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    String::from_utf8(result.stdout).unwrap()
+}
+
+#[test]
+fn text() {
+    let out = tempfile::tempdir().unwrap();
+    let output = out.path().join("config.h");
+    assert_eq!(
+        coverage("text", &output),
+        "config.h.in: 1 set, 1 undef, 1 unused\n  undef  config.h.in:2 HAVE_BAR\n  unused HAVE_UNUSED\n"
+    );
+    assert!(!output.exists(), "coverage wrote {}", output.display());
+}
+
+#[test]
+fn json() {
+    let out = tempfile::tempdir().unwrap();
+    let output = out.path().join("config.h");
+    let report: serde_json::Value = serde_json::from_str(&coverage("json", &output)).unwrap();
+    assert_eq!(
+        report,
+        serde_json::json!([{
+            "input": "config.h.in",
+            "set": ["HAVE_FOO"],
+            "undef": [{"key": "HAVE_BAR", "line": 2}],
+            "unused": ["HAVE_UNUSED"],
+        }])
+    );
+    assert!(!output.exists(), "coverage wrote {}", output.display());
+}
@@ -0,0 +1,79 @@
+//! Evaluates the configs of `tests/loader/*/`, which `load()` modules by every kind
+//! of label.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run(case: &str, args: &[&str]) -> Output {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/loader")
+        .join(case);
+    let out = tempfile::tempdir().unwrap();
+    Command::new(env!("CARGO_BIN_EXE_starconf"))
+        .current_dir(&dir)
+        .args(["--config", "config.star", "--compiler", "gcc", "--cc", "cc"])
+        .arg("--output")
+        .arg(out.path().join("config.h"))
+        .args(args)
+        .env("RUST_BACKTRACE", "0")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn labels() {
+    let result = run("labels", &["--load-path", "ext=ext"]);
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(result.status.success(), "{}", stderr);
+    for line in [
+        "relative: relative sibling",
+        "colon: colon",
+        "root: root relative sibling",
+        "named: named inner",
+        "bundled: function",
+        "cached: True",
+    ] {
+        assert!(
+            stderr.lines().any(|l| l == line),
+            "missing {:?} in:\n{}",
+            line,
+            stderr
+        );
+    }
+    // loaded by three labels, evaluated once
+    assert_eq!(
+        stderr.matches("evaluating relative.star").count(),
+        1,
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn missing_load_path() {
+    let result = run("labels", &[]);
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(!result.status.success());
+    assert!(stderr.contains("no --load-path named ext"), "{}", stderr);
+}
+
+#[test]
+fn cycle() {
+    let result = run("cycle", &[]);
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(!result.status.success());
+    assert!(!stderr.contains("overflow"), "{}", stderr);
+    let cycle = ["config.star", "first.star", "second.star", "config.star"]
+        .map(|name| {
+            let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/loader/cycle")
+                .join(name);
+            path.canonicalize().unwrap().display().to_string()
+        })
+        .join(" -> ");
+    assert!(
+        stderr.contains(&format!("load cycle: {}", cycle)),
+        "{}",
+        stderr
+    );
+}
@@ -0,0 +1,140 @@
+//! Declares the options of `tests/options/*/config.star` and reads them back with
+//! `get_option`, with and without `--option` overrides.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run(case: &str, options: &[&str]) -> Output {
+    let out = tempfile::tempdir().unwrap();
+    let mut command = Command::new(env!("CARGO_BIN_EXE_starconf"));
+    command
+        .current_dir(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/options")
+                .join(case),
+        )
+        .args(["--config", "config.star", "--compiler", "gcc", "--cc", "cc"])
+        .arg("--output")
+        .arg(out.path().join("config.h"))
+        .env("RUST_BACKTRACE", "0");
+    for option in options {
+        command.args(["--option", option]);
+    }
+    command.output().unwrap()
+}
+
+fn values(options: &[&str]) -> Vec<String> {
+    let result = run("declared", options);
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(result.status.success(), "{}", stderr);
+    stderr.lines().map(String::from).collect()
+}
+
+fn error(case: &str, options: &[&str]) -> String {
+    let result = run(case, options);
+    assert!(!result.status.success());
+    String::from_utf8_lossy(&result.stderr).into_owned()
+}
+
+#[test]
+fn defaults() {
+    assert_eq!(
+        values(&[]),
+        [
+            r#"name = "starconf""#,
+            "docs = False",
+            r#"backend = "one""#,
+            "jobs = 2",
+            r#"codecs = ["a"]"#,
+            "zlib = <feature auto>",
+            "implicit = True",
+        ]
+    );
+}
+
+#[test]
+fn overrides() {
+    assert_eq!(
+        values(&[
+            "name=x",
+            "docs=true",
+            "backend=two",
+            "jobs=8",
+            "codecs=b,c",
+            "zlib=disabled",
+            "implicit=false",
+        ]),
+        [
+            r#"name = "x""#,
+            "docs = True",
+            r#"backend = "two""#,
+            "jobs = 8",
+            r#"codecs = ["b", "c"]"#,
+            "zlib = <feature disabled>",
+            "implicit = False",
+        ]
+    );
+}
+
+#[test]
+fn invalid_values() {
+    for (option, message) in [
+        (
+            "backend=three",
+            r#"value `three` of option `backend` is not one of the choices ["one", "two"]"#,
+        ),
+        (
+            "codecs=a,d",
+            r#"value `d` of option `codecs` is not one of the choices ["a", "b", "c"]"#,
+        ),
+        ("jobs=0", "value 0 of option `jobs` is out of range [1, 8]"),
+        ("jobs=9", "value 9 of option `jobs` is out of range [1, 8]"),
+        (
+            "jobs=x",
+            "value `x` of integer option `jobs` is not an integer",
+        ),
+        (
+            "docs=yes",
+            "value `yes` of boolean option `docs` must be `true` or `false`",
+        ),
+        (
+            "zlib=maybe",
+            "value `maybe` of feature option `zlib` must be one of enabled, disabled, auto",
+        ),
+    ] {
+        let stderr = error("declared", &[option]);
+        assert!(stderr.contains(message), "{}: {}", option, stderr);
+    }
+}
+
+#[test]
+fn invalid_default() {
+    let stderr = error("bad_default", &[]);
+    assert!(
+        stderr.contains("value 10 of option `jobs` is out of range [1, 8]"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn unknown_option() {
+    let stderr = error("declared", &["nope=1"]);
+    assert!(
+        stderr.contains(
+            "unknown options: nope, declared options:\n  name: Name of the library\n  docs\n"
+        ),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn undeclared_option() {
+    let stderr = error("undeclared", &[]);
+    assert!(
+        stderr.contains("unknown option `missing`, did you call option() first"),
+        "{}",
+        stderr
+    );
+}